@@ -42,6 +42,12 @@
 #define secp256k1_ecmult s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult
 #define secp256k1_ecmult_gen_prec_table s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult_gen_prec_table
 #define secp256k1_ecmult_multi_var s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult_multi_var
+#define secp256k1_ellswift_create s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_create
+#define secp256k1_ellswift_decode s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_decode
+#define secp256k1_ellswift_encode s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_encode
+#define secp256k1_ellswift_xdh s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh
+#define secp256k1_ellswift_xdh_hash_function_bip324 s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_bip324
+#define secp256k1_ellswift_xdh_hash_function_prefix s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_prefix
 #define secp256k1_fe_add s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_add
 #define secp256k1_fe_cmp_var s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_cmp_var
 #define secp256k1_fe_const_b s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_const_b
@@ -25,6 +25,7 @@ fn main() {
         .define("ENABLE_MODULE_ECDH", Some("1"))
         .define("ENABLE_MODULE_SCHNORRSIG", Some("1"))
         .define("ENABLE_MODULE_EXTRAKEYS", Some("1"))
+        .define("ENABLE_MODULE_ELLSWIFT", Some("1"))
         .define("USE_NUM_NONE", Some("1"))
         .define("USE_FIELD_INV_BUILTIN", Some("1"))
         .define("USE_SCALAR_INV_BUILTIN", Some("1"))
@@ -41,6 +41,12 @@ pub use crate::bindings::{
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult as secp256k1_ecmult,
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult_gen_prec_table as secp256k1_ecmult_gen_prec_table,
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ecmult_multi_var as secp256k1_ecmult_multi_var,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_create as secp256k1_ellswift_create,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_decode as secp256k1_ellswift_decode,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_encode as secp256k1_ellswift_encode,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh as secp256k1_ellswift_xdh,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_bip324 as secp256k1_ellswift_xdh_hash_function_bip324,
+    s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_prefix as secp256k1_ellswift_xdh_hash_function_prefix,
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_add as secp256k1_fe_add,
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_cmp_var as secp256k1_fe_cmp_var,
     s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_fe_const_b as secp256k1_fe_const_b,
@@ -0,0 +1,521 @@
+use bech32::{segwit, Fe32};
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::{
+    base58,
+    errors::ConversionError,
+    keys::{self, Error as KeyError, PublicKey, XOnlyPublicKey},
+    network::Network,
+};
+
+#[derive(Debug, Clone)]
+/// Errors in address encoding and parsing
+pub enum Error {
+    /// The address had an unknown version byte or human readable part
+    UnknownNetwork,
+    /// The address is valid, but not for the requested network
+    WrongNetwork(Network),
+    /// The address was not a valid bech32 or bech32m segwit address
+    Segwit(String),
+    /// The witness version was above 16, or the program length was invalid for it
+    InvalidWitnessProgram,
+    /// Error tweaking the taproot output key
+    Key(KeyError),
+    /// Error converting a Base58Check address
+    Conversion(ConversionError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<KeyError> for Error {
+    fn from(e: KeyError) -> Self {
+        Error::Key(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The output script an address pays to
+pub enum Payload {
+    /// P2PKH, paying to the HASH160 of a public key
+    PubkeyHash([u8; 20]),
+    /// P2SH, paying to the HASH160 of a script
+    ScriptHash([u8; 20]),
+    /// P2WPKH, a version 0 witness program holding the HASH160 of a compressed public key
+    WitnessPubkeyHash([u8; 20]),
+    /// P2TR, a version 1 witness program holding a taproot output key
+    Taproot([u8; 32]),
+    /// Any other witness program, such as P2WSH or a future version
+    WitnessProgram {
+        /// The witness version, from 0 to 16
+        version: u8,
+        /// The witness program, from 2 to 40 bytes
+        program: Vec<u8>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A Bitcoin address, which is a payload encoded for a network; its fields are private so every address can be displayed
+pub struct Address {
+    /// The network the address is encoded for
+    network: Network,
+    /// What the address pays to
+    payload: Payload,
+}
+
+impl Address {
+    /// Construct an address for payload, failing if it is a witness program which BIP141 does not allow
+    pub fn new(payload: Payload, network: Network) -> Result<Self, Error> {
+        let payload = match payload {
+            Payload::WitnessProgram { version, program } => witness_payload(version, program)?,
+            payload => payload,
+        };
+
+        Ok(Self { network, payload })
+    }
+
+    /// Return the network the address is encoded for
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Return what the address pays to
+    pub fn payload(&self) -> &Payload {
+        &self.payload
+    }
+
+    /// Construct a P2PKH address for the compressed public key
+    pub fn p2pkh(public_key: &PublicKey, network: Network) -> Self {
+        Self {
+            network,
+            payload: Payload::PubkeyHash(public_key.hash160()),
+        }
+    }
+
+    /// Construct a P2WPKH address for the compressed public key
+    pub fn p2wpkh(public_key: &PublicKey, network: Network) -> Self {
+        Self {
+            network,
+            payload: Payload::WitnessPubkeyHash(public_key.hash160()),
+        }
+    }
+
+    /// Construct a P2TR address by tweaking internal_key with the merkle root of its script tree as BIP341 specifies, or with no scripts if merkle_root is None
+    pub fn p2tr(
+        internal_key: &XOnlyPublicKey,
+        merkle_root: Option<&[u8; 32]>,
+        network: Network,
+    ) -> Result<Self, Error> {
+        let mut msg = internal_key.to_bytes().to_vec();
+        if let Some(root) = merkle_root {
+            msg.extend_from_slice(root);
+        }
+        let tweak = keys::tagged_hash(b"TapTweak", &msg);
+
+        let (output_key, _parity) = internal_key.tweak_add(&tweak)?;
+
+        Ok(Self::p2tr_tweaked(&output_key, network))
+    }
+
+    /// Construct a P2TR address for an output key which has already been tweaked
+    pub fn p2tr_tweaked(output_key: &XOnlyPublicKey, network: Network) -> Self {
+        Self {
+            network,
+            payload: Payload::Taproot(output_key.to_bytes()),
+        }
+    }
+
+    /// Parse an address, failing unless it is valid for network; signet and regtest share encodings with testnet, so those networks accept each other's Base58Check addresses
+    pub fn parse(s: &str, network: Network) -> Result<Self, Error> {
+        let mut address = Self::try_from(s)?;
+
+        if !address.is_valid_for_network(network) {
+            return Err(Error::WrongNetwork(address.network));
+        }
+        address.network = network;
+
+        Ok(address)
+    }
+
+    /// Return true if this address encodes identically for network
+    pub fn is_valid_for_network(&self, network: Network) -> bool {
+        match self.payload {
+            Payload::PubkeyHash(_) | Payload::ScriptHash(_) => {
+                self.network.pubkey_hash_version() == network.pubkey_hash_version()
+            }
+            _ => self.network.hrp() == network.hrp(),
+        }
+    }
+
+    /// Return the scriptPubKey which this address pays to
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        match &self.payload {
+            Payload::PubkeyHash(hash) => [&[0x76, 0xA9, 0x14][..], hash, &[0x88, 0xAC]].concat(),
+            Payload::ScriptHash(hash) => [&[0xA9, 0x14][..], hash, &[0x87]].concat(),
+            _ => {
+                let (version, program) = self.witness_program().unwrap();
+                let op = if version == 0 { 0 } else { 0x50 + version };
+                [&[op, program.len() as u8][..], program].concat()
+            }
+        }
+    }
+
+    fn witness_program(&self) -> Option<(u8, &[u8])> {
+        match &self.payload {
+            Payload::WitnessPubkeyHash(hash) => Some((0, hash)),
+            Payload::Taproot(key) => Some((1, key)),
+            Payload::WitnessProgram { version, program } => Some((*version, program)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (version, hash) = match &self.payload {
+            Payload::PubkeyHash(hash) => (self.network.pubkey_hash_version(), hash),
+            Payload::ScriptHash(hash) => (self.network.script_hash_version(), hash),
+            _ => {
+                // construction checked the version is at most 16, so it is a single bech32 character
+                let (version, program) = self.witness_program().unwrap();
+                let version = Fe32::try_from(version).unwrap();
+                return segwit::encode_lower_to_fmt_unchecked(
+                    f,
+                    self.network.hrp(),
+                    version,
+                    program,
+                );
+            }
+        };
+
+        let mut data = vec![version];
+        data.extend_from_slice(hash);
+        write!(f, "{}", base58::encode_check(&data))
+    }
+}
+
+impl TryFrom<&str> for Address {
+    type Error = Error;
+
+    /// Parse an address of any network; signet addresses can't be told apart from testnet ones, and regtest Base58Check addresses can't either, so all of those parse as testnet
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match segwit::decode(s) {
+            Ok((hrp, version, program)) => {
+                let network = [Network::Mainnet, Network::Testnet, Network::Regtest]
+                    .into_iter()
+                    .find(|n| n.hrp() == hrp)
+                    .ok_or(Error::UnknownNetwork)?;
+
+                let payload = witness_payload(version.to_u8(), program)?;
+
+                Ok(Self { network, payload })
+            }
+            // no Base58Check address starts with a segwit prefix, so report why it isn't valid segwit
+            Err(e) if has_segwit_prefix(s) => Err(Error::Segwit(e.to_string())),
+            Err(_) => {
+                let data = match base58::decode_check(s) {
+                    Ok(data) => data,
+                    Err(e) => return Err(Error::Conversion(ConversionError::Base58(e))),
+                };
+                if data.len() != 21 {
+                    return Err(Error::Conversion(ConversionError::WrongNumberOfBytes(
+                        data.len(),
+                    )));
+                }
+                let hash: [u8; 20] = data[1..].try_into().unwrap();
+
+                let (network, payload) = match data[0] {
+                    0x00 => (Network::Mainnet, Payload::PubkeyHash(hash)),
+                    0x05 => (Network::Mainnet, Payload::ScriptHash(hash)),
+                    0x6F => (Network::Testnet, Payload::PubkeyHash(hash)),
+                    0xC4 => (Network::Testnet, Payload::ScriptHash(hash)),
+                    _ => return Err(Error::UnknownNetwork),
+                };
+
+                Ok(Self { network, payload })
+            }
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    /// Parse an address of any network, as TryFrom does
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Address::try_from(s)
+    }
+}
+
+/// Check the witness program as BIP141 does, using the dedicated variants for P2WPKH and P2TR so equal addresses compare equal
+fn witness_payload(version: u8, program: Vec<u8>) -> Result<Payload, Error> {
+    match (version, program.len()) {
+        (0, 20) => Ok(Payload::WitnessPubkeyHash(program[..].try_into().unwrap())),
+        (0, 32) => Ok(Payload::WitnessProgram { version, program }),
+        (0, _) => Err(Error::InvalidWitnessProgram),
+        (1, 32) => Ok(Payload::Taproot(program[..].try_into().unwrap())),
+        (1..=16, 2..=40) => Ok(Payload::WitnessProgram { version, program }),
+        _ => Err(Error::InvalidWitnessProgram),
+    }
+}
+
+fn has_segwit_prefix(s: &str) -> bool {
+    let s = s.to_lowercase();
+    ["bc1", "tb1", "bcrt1"].iter().any(|p| s.starts_with(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{errors::Base58Error, hd::DerivationPath, mnemonic::Mnemonic, scalar::Scalar};
+
+    fn check_valid(address: &str, script: &str) {
+        let a = Address::try_from(address).unwrap();
+        assert_eq!(hex::encode(a.script_pubkey()), script, "{}", address);
+        assert_eq!(a.to_string(), address.to_lowercase());
+    }
+
+    #[test]
+    fn bip173_bip350_valid() {
+        let vectors = [
+            (
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                "0014751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+                "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+            ),
+            (
+                "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+                "5128751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            ("BC1SW50QGDZ25J", "6002751e"),
+            (
+                "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs",
+                "5210751e76e8199196d454941c45d1b3a323",
+            ),
+            (
+                "tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy",
+                "0020000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+            (
+                "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                "5120000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+        ];
+
+        for (address, script) in vectors {
+            check_valid(address, script);
+        }
+    }
+
+    #[test]
+    fn bip173_bip350_invalid() {
+        let vectors = [
+            // invalid human readable part
+            "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut",
+            // bech32 checksum where bech32m is required, and vice versa
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+            "tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf",
+            "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh",
+            "tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47",
+            // invalid character in checksum
+            "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+            // invalid witness version
+            "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+            // invalid program lengths
+            "bc1pw5dgrnzv",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+            "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P",
+            // mixed case
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+            // bad padding
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+            // empty data
+            "bc1gmk9yu",
+            // BIP173 invalid vectors not already covered
+            "tc1qw508d6qejxtdg4y5r3zarvary0c5xw7kg3g4ty",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+            "bc10w508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kw5rljs90",
+            "bc1zw508d6qejxtdg4y5r3zarvaryvqyzf3du",
+        ];
+
+        for address in vectors {
+            assert!(Address::try_from(address).is_err(), "{}", address);
+        }
+    }
+
+    #[test]
+    fn from_keys() {
+        // BIP44, BIP84 and BIP86 first receive addresses for the all abandon mnemonic
+        let mnemonic = Mnemonic::try_from(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let seed = mnemonic.to_seed("");
+        let key = |path: &str| {
+            seed.master_key(Network::Mainnet)
+                .unwrap()
+                .derive_path(&DerivationPath::try_from(path).unwrap())
+                .unwrap()
+                .key_pair()
+        };
+
+        let p2pkh = Address::p2pkh(&key("m/44'/0'/0'/0/0").public_key(), Network::Mainnet);
+        assert_eq!(p2pkh.to_string(), "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+
+        let p2wpkh = Address::p2wpkh(&key("m/84'/0'/0'/0/0").public_key(), Network::Mainnet);
+        assert_eq!(
+            p2wpkh.to_string(),
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+
+        let internal = key("m/86'/0'/0'/0/0").xonly_public_key();
+        let p2tr = Address::p2tr(&internal, None, Network::Mainnet).unwrap();
+        assert_eq!(
+            hex::encode(internal.to_bytes()),
+            "cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"
+        );
+        assert_eq!(
+            p2tr.to_string(),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+
+        for a in [p2pkh, p2wpkh, p2tr] {
+            assert_eq!(Address::from_str(&a.to_string()).unwrap(), a);
+        }
+    }
+
+    #[test]
+    fn networks() {
+        let key = PublicKey::new(&Scalar::from(1)).unwrap();
+
+        let p2pkh = Address::p2pkh(&key, Network::Mainnet);
+        assert_eq!(p2pkh.to_string(), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+
+        let p2pkh = Address::p2pkh(&key, Network::Regtest);
+        assert_eq!(p2pkh.to_string(), "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r");
+        assert_eq!(
+            Address::try_from("mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r")
+                .unwrap()
+                .network(),
+            Network::Testnet
+        );
+        assert_eq!(
+            Address::parse("mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r", Network::Regtest).unwrap(),
+            p2pkh
+        );
+
+        let signet = Address::p2wpkh(&key, Network::Signet);
+        let regtest = Address::p2wpkh(&key, Network::Regtest);
+        assert_eq!(
+            signet.to_string(),
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        );
+        assert_eq!(
+            regtest.to_string(),
+            "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+        );
+        assert_eq!(
+            Address::try_from(regtest.to_string().as_str()).unwrap(),
+            regtest
+        );
+        assert_eq!(
+            Address::parse(&signet.to_string(), Network::Signet).unwrap(),
+            signet
+        );
+        assert!(matches!(
+            Address::parse(&signet.to_string(), Network::Mainnet),
+            Err(Error::WrongNetwork(Network::Testnet))
+        ));
+        assert!(matches!(
+            Address::parse(&regtest.to_string(), Network::Testnet),
+            Err(Error::WrongNetwork(Network::Regtest))
+        ));
+
+        assert!(matches!(
+            Address::try_from("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMI"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Decode
+            )))
+        ));
+        assert!(matches!(
+            Address::try_from("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        assert!(matches!(
+            Address::try_from("tc1qw508d6qejxtdg4y5r3zarvary0c5xw7kg3g4ty"),
+            Err(Error::UnknownNetwork)
+        ));
+    }
+
+    #[test]
+    fn witness_programs() {
+        let key = PublicKey::new(&Scalar::from(1)).unwrap();
+        let p2wpkh = Address::p2wpkh(&key, Network::Mainnet);
+
+        // the dedicated variants are used whatever the payload was constructed as
+        let a = Address::new(
+            Payload::WitnessProgram {
+                version: 0,
+                program: key.hash160().to_vec(),
+            },
+            Network::Mainnet,
+        )
+        .unwrap();
+        assert_eq!(a, p2wpkh);
+        assert_eq!(a.payload(), p2wpkh.payload());
+
+        let a = Address::new(
+            Payload::WitnessProgram {
+                version: 16,
+                program: vec![0x75, 0x1e],
+            },
+            Network::Mainnet,
+        )
+        .unwrap();
+        assert_eq!(a.to_string(), "bc1sw50qgdz25j");
+        assert_eq!(Address::try_from(a.to_string().as_str()).unwrap(), a);
+
+        // versions above 16 and bad lengths would display as addresses which can't be parsed, or not at all
+        for (version, len) in [
+            (0, 21),
+            (0, 33),
+            (1, 1),
+            (2, 41),
+            (17, 32),
+            (31, 32),
+            (32, 32),
+            (255, 20),
+        ] {
+            let payload = Payload::WitnessProgram {
+                version,
+                program: vec![0; len],
+            };
+            assert!(
+                matches!(
+                    Address::new(payload, Network::Mainnet),
+                    Err(Error::InvalidWitnessProgram)
+                ),
+                "version {} length {}",
+                version,
+                len
+            );
+        }
+    }
+}
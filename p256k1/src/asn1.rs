@@ -0,0 +1,356 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use zeroize::Zeroizing;
+
+/// The tag of an INTEGER
+pub const INTEGER: u8 = 0x02;
+/// The tag of a BIT STRING
+pub const BIT_STRING: u8 = 0x03;
+/// The tag of an OCTET STRING
+pub const OCTET_STRING: u8 = 0x04;
+/// The tag of an OBJECT IDENTIFIER
+pub const OBJECT_IDENTIFIER: u8 = 0x06;
+/// The tag of a SEQUENCE
+pub const SEQUENCE: u8 = 0x30;
+
+/// Return the tag of the explicit context specific constructed field n
+pub const fn context(n: u8) -> u8 {
+    0xA0 | n
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors reading DER or PEM
+pub enum Error {
+    /// The data ended before the element did
+    Truncated,
+    /// An element had a different tag than expected
+    UnexpectedTag(u8),
+    /// A length was indefinite, too large, or not minimally encoded
+    InvalidLength,
+    /// There were bytes left over after the last element
+    TrailingData,
+    /// The PEM block with the expected label was missing or malformed
+    InvalidPem,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A reader over a sequence of DER elements
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Construct a reader over the passed DER
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Return true if every element has been read
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Return the tag of the next element, if any
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    /// Read the next element, which must have the passed tag, and return its contents
+    pub fn read(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        let (t, rest) = self.data.split_first().ok_or(Error::Truncated)?;
+        if *t != tag {
+            return Err(Error::UnexpectedTag(*t));
+        }
+
+        let (first, rest) = rest.split_first().ok_or(Error::Truncated)?;
+        let (len, rest) = if first & 0x80 == 0 {
+            (*first as usize, rest)
+        } else {
+            let n = (first & 0x7F) as usize;
+            if n == 0 || n > 2 || rest.len() < n || rest[0] == 0 {
+                return Err(Error::InvalidLength);
+            }
+            let len = rest[..n].iter().fold(0, |l, b| (l << 8) | *b as usize);
+            if len < 0x80 {
+                return Err(Error::InvalidLength);
+            }
+            (len, &rest[n..])
+        };
+
+        if rest.len() < len {
+            return Err(Error::Truncated);
+        }
+        let (value, rest) = rest.split_at(len);
+        self.data = rest;
+
+        Ok(value)
+    }
+
+    /// Read an INTEGER which fits in a byte
+    pub fn read_small_integer(&mut self) -> Result<u8, Error> {
+        match self.read(INTEGER)? {
+            [n] if *n < 0x80 => Ok(*n),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// Read a BIT STRING with no unused bits, and return its bytes
+    pub fn read_bit_string(&mut self) -> Result<&'a [u8], Error> {
+        match self.read(BIT_STRING)?.split_first() {
+            Some((0, bits)) => Ok(bits),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// Fail unless every element has been read
+    pub fn finish(&self) -> Result<(), Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::TrailingData)
+        }
+    }
+}
+
+/// Append an element with the passed tag and contents to out
+pub fn write(out: &mut Vec<u8>, tag: u8, value: &[u8]) {
+    out.push(tag);
+    write_len(out, value.len());
+    out.extend_from_slice(value);
+}
+
+/// Append an INTEGER which fits in a byte to out
+pub fn write_small_integer(out: &mut Vec<u8>, n: u8) {
+    write(out, INTEGER, &[n]);
+}
+
+/// Append a BIT STRING with no unused bits to out
+pub fn write_bit_string(out: &mut Vec<u8>, bits: &[u8]) {
+    out.push(BIT_STRING);
+    write_len(out, bits.len() + 1);
+    out.push(0);
+    out.extend_from_slice(bits);
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    if len < 0x80 {
+        out.push(len as u8);
+    } else if len < 0x100 {
+        out.extend_from_slice(&[0x81, len as u8]);
+    } else {
+        out.extend_from_slice(&[0x82, (len >> 8) as u8, len as u8]);
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Wrap DER in a PEM block with the passed label, breaking the base64 into 64 character lines; the string is allocated at its final size, so wrapping secret DER leaves no stray copies behind
+pub fn pem_encode(label: &str, der: &[u8]) -> String {
+    let begin = format!("-----BEGIN {}-----\n", label);
+    let end = format!("-----END {}-----\n", label);
+    let lines = der.len().div_ceil(48);
+
+    let mut s = String::with_capacity(begin.len() + der.len().div_ceil(3) * 4 + lines + end.len());
+    s.push_str(&begin);
+    // 48 bytes is exactly one 64 character line
+    for line in der.chunks(48) {
+        encode_into(&mut s, BASE64, line, true);
+        s.push('\n');
+    }
+    s.push_str(&end);
+
+    s
+}
+
+/// Return the DER in the first PEM block with the passed label, skipping any others such as EC PARAMETERS
+pub fn pem_decode(label: &str, pem: &str) -> Result<Vec<u8>, Error> {
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+
+    let start = pem.find(&begin).ok_or(Error::InvalidPem)? + begin.len();
+    let len = pem[start..].find(&end).ok_or(Error::InvalidPem)?;
+
+    base64_decode(&pem[start..start + len])
+}
+
+/// Encode data as unpadded base64url, as JOSE uses
+pub(crate) fn base64url_encode(data: &[u8]) -> String {
+    encode(BASE64URL, data, false)
+}
+
+/// Decode unpadded base64url, returning None if it is malformed
+pub(crate) fn base64url_decode(s: &str) -> Option<Vec<u8>> {
+    decode(BASE64URL, s.as_bytes(), false)
+}
+
+fn base64_decode(s: &str) -> Result<Vec<u8>, Error> {
+    let mut digits = Zeroizing::new(Vec::with_capacity(s.len()));
+    digits.extend(s.bytes().filter(|c| !c.is_ascii_whitespace()));
+
+    decode(BASE64, &digits, true).ok_or(Error::InvalidPem)
+}
+
+fn encode(alphabet: &[u8; 64], data: &[u8], pad: bool) -> String {
+    let mut s = String::with_capacity(data.len().div_ceil(3) * 4);
+    encode_into(&mut s, alphabet, data, pad);
+
+    s
+}
+
+fn encode_into(s: &mut String, alphabet: &[u8; 64], data: &[u8], pad: bool) {
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(alphabet[(n >> (18 - 6 * i)) as usize & 0x3F] as char);
+            } else if pad {
+                s.push('=');
+            }
+        }
+    }
+}
+
+fn decode(alphabet: &[u8; 64], digits: &[u8], pad: bool) -> Option<Vec<u8>> {
+    match digits.len() % 4 {
+        0 => {}
+        2 | 3 if !pad => {}
+        _ => return None,
+    }
+
+    // the output may be secret, so it is allocated at its largest size and zeroed if decoding fails
+    let mut out = Zeroizing::new(Vec::with_capacity(digits.len() / 4 * 3 + 2));
+    let chunks = digits.len().div_ceil(4);
+    for (i, chunk) in digits.chunks(4).enumerate() {
+        let padding = if pad {
+            chunk.iter().rev().take_while(|c| **c == b'=').count()
+        } else {
+            0
+        };
+        if padding > 2 || (padding > 0 && i != chunks - 1) {
+            return None;
+        }
+
+        let chunk = &chunk[..chunk.len() - padding];
+        let mut n = 0u32;
+        for c in chunk {
+            let d = alphabet.iter().position(|x| x == c)?;
+            n = (n << 6) | d as u32;
+        }
+        // a short final chunk has bits left over after its last byte, which must be zero so each encoding is unique
+        if n & ((1 << ((6 * chunk.len()) % 8)) - 1) != 0 {
+            return None;
+        }
+        n <<= 6 * (4 - chunk.len());
+
+        out.extend_from_slice(&n.to_be_bytes()[1..chunk.len()]);
+    }
+
+    Some(core::mem::take(&mut *out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (plain, b64) in vectors {
+            assert_eq!(encode(BASE64, plain.as_bytes(), true), b64);
+            assert_eq!(base64_decode(b64).unwrap(), plain.as_bytes());
+        }
+
+        assert!(base64_decode("Zm9").is_err());
+        assert!(base64_decode("Z===").is_err());
+        assert!(base64_decode("Zg==Zg==").is_err());
+        assert!(base64_decode("Zm9*").is_err());
+        // "Zh==" and "Zm9=" have unused bits set, so aren't the encodings of "f" and "fo"
+        assert!(base64_decode("Zh==").is_err());
+        assert!(base64_decode("Zm9=").is_err());
+        assert!(pem_decode("TEST", "-----BEGIN TEST-----\nZh==\n-----END TEST-----\n").is_err());
+
+        for (plain, b64) in vectors {
+            let url = b64.trim_end_matches('=');
+            assert_eq!(base64url_encode(plain.as_bytes()), url);
+            assert_eq!(base64url_decode(url).unwrap(), plain.as_bytes());
+        }
+
+        assert_eq!(base64url_encode(&[0xFB, 0xFF]), "-_8");
+        assert_eq!(base64url_decode("-_8").unwrap(), [0xFB, 0xFF]);
+        assert!(base64url_decode("Zg==").is_none());
+        assert!(base64url_decode("Zm9vY").is_none());
+        assert!(base64url_decode("+/8").is_none());
+        assert!(base64url_decode("Zh").is_none());
+        assert!(base64url_decode("Zm9").is_none());
+        assert!(base64url_decode("-_9").is_none());
+    }
+
+    #[test]
+    fn pem() {
+        for len in [0, 1, 47, 48, 49, 95, 96, 200] {
+            let der: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let pem = pem_encode("TEST", &der);
+
+            // the string never grew, so never left a copy of its contents behind
+            assert_eq!(pem.capacity(), pem.len());
+            assert!(pem.lines().all(|line| line.len() <= 64));
+            assert_eq!(pem_decode("TEST", &pem).unwrap(), der);
+        }
+    }
+
+    #[test]
+    fn der() {
+        let mut out = Vec::new();
+        write_small_integer(&mut out, 1);
+        write(&mut out, OCTET_STRING, &[0xAA; 0x90]);
+        write_bit_string(&mut out, &[0xBB; 0x7F]);
+
+        assert_eq!(&out[..3], &[INTEGER, 1, 1]);
+        assert_eq!(&out[3..6], &[OCTET_STRING, 0x81, 0x90]);
+        assert_eq!(&out[0x96..0x9A], &[BIT_STRING, 0x81, 0x80, 0]);
+
+        let mut r = Reader::new(&out);
+        assert_eq!(r.read_small_integer().unwrap(), 1);
+        assert_eq!(r.peek_tag(), Some(OCTET_STRING));
+        assert_eq!(r.read(OCTET_STRING).unwrap(), &[0xAA; 0x90][..]);
+        assert_eq!(r.read(OCTET_STRING), Err(Error::UnexpectedTag(BIT_STRING)));
+        assert_eq!(r.read_bit_string().unwrap(), &[0xBB; 0x7F][..]);
+        assert!(r.finish().is_ok());
+
+        // non-minimal and truncated lengths
+        assert_eq!(
+            Reader::new(&[OCTET_STRING, 0x81, 0x01, 0]).read(OCTET_STRING),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            Reader::new(&[OCTET_STRING, 0x80]).read(OCTET_STRING),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            Reader::new(&[OCTET_STRING, 2, 0]).read(OCTET_STRING),
+            Err(Error::Truncated)
+        );
+    }
+}
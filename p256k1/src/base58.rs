@@ -0,0 +1,57 @@
+use sha2::{Digest, Sha256};
+
+use crate::errors::Base58Error;
+
+/// Encode data with a 4 byte double SHA-256 checksum appended, as in Bitcoin's Base58Check
+pub fn encode_check(data: &[u8]) -> String {
+    let mut v = data.to_vec();
+    v.extend_from_slice(&checksum(data));
+
+    bs58::encode(v).into_string()
+}
+
+/// Decode a Base58Check string, verifying and stripping its checksum
+pub fn decode_check(s: &str) -> Result<Vec<u8>, Base58Error> {
+    let mut v = match bs58::decode(s).into_vec() {
+        Ok(v) => v,
+        Err(_e) => return Err(Base58Error::Decode),
+    };
+
+    if v.len() < 4 {
+        return Err(Base58Error::Decode);
+    }
+
+    let check = v.split_off(v.len() - 4);
+    if check[..] != checksum(&v) {
+        return Err(Base58Error::Checksum);
+    }
+
+    Ok(v)
+}
+
+fn checksum(data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    let mut c = [0u8; 4];
+    c.copy_from_slice(&hash[..4]);
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for data in [&b""[..], &[0u8, 0, 1][..], &b"hello world"[..]] {
+            let s = encode_check(data);
+            assert_eq!(decode_check(&s).unwrap(), data);
+        }
+
+        // the checksum of the empty string is 5df6e0e2
+        assert_eq!(encode_check(&[]), "3QJmnh");
+        assert_eq!(decode_check("3QJmni"), Err(Base58Error::Checksum));
+        assert_eq!(decode_check("3QJmn"), Err(Base58Error::Checksum));
+        assert_eq!(decode_check("1"), Err(Base58Error::Decode));
+        assert_eq!(decode_check("0OIl"), Err(Base58Error::Decode));
+    }
+}
@@ -943,6 +943,59 @@ extern "C" {
         pubkey: *const secp256k1_xonly_pubkey,
     ) -> ::std::os::raw::c_int;
 }
+#[doc = " A pointer to a function used by secp256k1_ellswift_xdh to hash the shared X\n  coordinate along with the encoded public keys to a uniform shared secret.\n\n  Returns: 1 if a shared secret was successfully computed.\n           0 will cause secp256k1_ellswift_xdh to fail and return 0.\n           Other return values are not allowed, and the behaviour of\n           secp256k1_ellswift_xdh is undefined for other return values.\n  Out:     output:     pointer to an array to be filled by the function\n  In:      x32:        pointer to the 32-byte serialized X coordinate\n                       of the resulting shared point (will not be NULL)\n           ell_a64:    pointer to the 64-byte encoded public key of party A\n                       (will not be NULL)\n           ell_b64:    pointer to the 64-byte encoded public key of party B\n                       (will not be NULL)\n           data:       arbitrary data pointer that is passed through"]
+pub type secp256k1_ellswift_xdh_hash_function = ::std::option::Option<
+    unsafe extern "C" fn(
+        output: *mut ::std::os::raw::c_uchar,
+        x32: *const ::std::os::raw::c_uchar,
+        ell_a64: *const ::std::os::raw::c_uchar,
+        ell_b64: *const ::std::os::raw::c_uchar,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+extern "C" {
+    pub static s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_prefix:
+        secp256k1_ellswift_xdh_hash_function;
+}
+extern "C" {
+    pub static s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh_hash_function_bip324:
+        secp256k1_ellswift_xdh_hash_function;
+}
+extern "C" {
+    pub fn s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_encode(
+        ctx: *const secp256k1_context,
+        ell64: *mut ::std::os::raw::c_uchar,
+        pubkey: *const secp256k1_pubkey,
+        rnd32: *const ::std::os::raw::c_uchar,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_decode(
+        ctx: *const secp256k1_context,
+        pubkey: *mut secp256k1_pubkey,
+        ell64: *const ::std::os::raw::c_uchar,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_create(
+        ctx: *const secp256k1_context,
+        ell64: *mut ::std::os::raw::c_uchar,
+        seckey32: *const ::std::os::raw::c_uchar,
+        auxrnd32: *const ::std::os::raw::c_uchar,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn s2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480_secp256k1_ellswift_xdh(
+        ctx: *const secp256k1_context,
+        output: *mut ::std::os::raw::c_uchar,
+        ell_a64: *const ::std::os::raw::c_uchar,
+        ell_b64: *const ::std::os::raw::c_uchar,
+        seckey32: *const ::std::os::raw::c_uchar,
+        party: ::std::os::raw::c_int,
+        hashfp: secp256k1_ellswift_xdh_hash_function,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
 pub type _Float32 = f32;
 pub type _Float64 = f64;
 pub type _Float32x = f64;
@@ -0,0 +1,356 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use sha2::{Digest, Sha256};
+
+use crate::keys::PublicKey;
+
+/// The Crockford base32 alphabet, which omits I, L, O and U
+const C32_CHARACTERS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The version of mainnet single signature addresses, which start SP
+pub const MAINNET_SINGLESIG: u8 = 22;
+/// The version of mainnet multisig addresses, which start SM
+pub const MAINNET_MULTISIG: u8 = 20;
+/// The version of testnet single signature addresses, which start ST
+pub const TESTNET_SINGLESIG: u8 = 26;
+/// The version of testnet multisig addresses, which start SN
+pub const TESTNET_MULTISIG: u8 = 21;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors in c32 encoding and decoding
+pub enum Error {
+    /// The string had a character outside the c32 alphabet
+    InvalidCharacter(char),
+    /// The version was not less than 32, so can't be encoded as a single c32 character
+    InvalidVersion(u8),
+    /// The checksum didn't match the decoded data
+    InvalidChecksum,
+    /// The string was too short to hold a version and checksum
+    TooShort,
+    /// The address didn't start with S
+    InvalidPrefix,
+    /// The multisig threshold was zero or more than the number of keys, or there were more than 16 keys
+    InvalidMultisig,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Encode bytes as c32, with one leading 0 for each leading zero byte
+pub fn encode(data: &[u8]) -> String {
+    let mut result = Vec::with_capacity(data.len() * 8 / 5 + 1);
+    let mut carry = 0u16;
+    let mut carry_bits = 0;
+
+    for byte in data.iter().rev() {
+        carry |= (*byte as u16) << carry_bits;
+        carry_bits += 8;
+
+        while carry_bits >= 5 {
+            result.push(C32_CHARACTERS[(carry & 0x1F) as usize]);
+            carry >>= 5;
+            carry_bits -= 5;
+        }
+    }
+    if carry_bits > 0 {
+        result.push(C32_CHARACTERS[carry as usize]);
+    }
+
+    // strip the zero digits of the number, then add back one per leading zero byte
+    while result.last() == Some(&C32_CHARACTERS[0]) {
+        result.pop();
+    }
+    let zeros = data.iter().take_while(|b| **b == 0).count();
+    result.resize(result.len() + zeros, C32_CHARACTERS[0]);
+
+    result.reverse();
+    String::from_utf8(result).unwrap()
+}
+
+/// Decode c32 into bytes, with one leading zero byte for each leading 0; case is ignored, and O, I and L are read as 0, 1 and 1
+pub fn decode(s: &str) -> Result<Vec<u8>, Error> {
+    let digits = s.chars().map(digit).collect::<Result<Vec<u8>, Error>>()?;
+
+    let mut result = Vec::with_capacity(digits.len() * 5 / 8 + 1);
+    let mut carry = 0u16;
+    let mut carry_bits = 0;
+
+    for d in digits.iter().rev() {
+        carry |= (*d as u16) << carry_bits;
+        carry_bits += 5;
+
+        if carry_bits >= 8 {
+            result.push((carry & 0xFF) as u8);
+            carry >>= 8;
+            carry_bits -= 8;
+        }
+    }
+    if carry_bits > 0 {
+        result.push(carry as u8);
+    }
+
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    let zeros = digits.iter().take_while(|d| **d == 0).count();
+    result.resize(result.len() + zeros, 0);
+
+    result.reverse();
+    Ok(result)
+}
+
+/// Encode data as c32check: the version as a single c32 character, followed by the c32 of data and a 4 byte double SHA-256 checksum over the version and data
+pub fn check_encode(version: u8, data: &[u8]) -> Result<String, Error> {
+    if version >= 32 {
+        return Err(Error::InvalidVersion(version));
+    }
+
+    let mut v = data.to_vec();
+    v.extend_from_slice(&checksum(version, data));
+
+    let mut s = String::with_capacity(1 + v.len() * 8 / 5 + 1);
+    s.push(C32_CHARACTERS[version as usize] as char);
+    s.push_str(&encode(&v));
+
+    Ok(s)
+}
+
+/// Decode a c32check string into its version and data, verifying and stripping its checksum
+pub fn check_decode(s: &str) -> Result<(u8, Vec<u8>), Error> {
+    let mut chars = s.chars();
+    let version = match chars.next() {
+        Some(c) => digit(c)?,
+        None => return Err(Error::TooShort),
+    };
+
+    let mut data = decode(chars.as_str())?;
+    if data.len() < 4 {
+        return Err(Error::TooShort);
+    }
+
+    let check = data.split_off(data.len() - 4);
+    if check[..] != checksum(version, &data) {
+        return Err(Error::InvalidChecksum);
+    }
+
+    Ok((version, data))
+}
+
+/// Encode a 20 byte hash as a Stacks address, which is S followed by the c32check of the version and hash
+pub fn address(version: u8, hash: &[u8; 20]) -> Result<String, Error> {
+    Ok(format!("S{}", check_encode(version, hash)?))
+}
+
+/// Decode a Stacks address into its version and 20 byte hash
+pub fn address_decode(s: &str) -> Result<(u8, [u8; 20]), Error> {
+    let rest = match s.strip_prefix('S') {
+        Some(rest) => rest,
+        None => return Err(Error::InvalidPrefix),
+    };
+
+    let (version, data) = check_decode(rest)?;
+    match data.try_into() {
+        Ok(hash) => Ok((version, hash)),
+        Err(_) => Err(Error::TooShort),
+    }
+}
+
+/// Return the Stacks address of the compressed public key, such as MAINNET_SINGLESIG or TESTNET_SINGLESIG
+pub fn stacks_address(public_key: &PublicKey, version: u8) -> Result<String, Error> {
+    address(version, &public_key.hash160())
+}
+
+/// Return the Stacks address of an m of n multisig over the compressed public keys, such as MAINNET_MULTISIG or TESTNET_MULTISIG, which hashes the same redeem script as a Bitcoin P2SH multisig
+pub fn stacks_multisig_address(
+    threshold: usize,
+    public_keys: &[PublicKey],
+    version: u8,
+) -> Result<String, Error> {
+    address(version, &multisig_hash(threshold, public_keys)?)
+}
+
+/// Return the HASH160 of the redeem script `OP_m <keys> OP_n OP_CHECKMULTISIG`
+pub fn multisig_hash(threshold: usize, public_keys: &[PublicKey]) -> Result<[u8; 20], Error> {
+    let n = public_keys.len();
+    if threshold == 0 || threshold > n || n > 16 {
+        return Err(Error::InvalidMultisig);
+    }
+
+    let mut script = Vec::with_capacity(3 + 34 * n);
+    script.push(0x50 + threshold as u8);
+    for key in public_keys {
+        script.push(33);
+        script.extend_from_slice(&key.to_bytes());
+    }
+    script.push(0x50 + n as u8);
+    script.push(0xAE);
+
+    let sha = Sha256::digest(&script);
+    Ok(ripemd::Ripemd160::digest(sha).into())
+}
+
+fn digit(c: char) -> Result<u8, Error> {
+    let n = match c.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        c => c,
+    };
+
+    match C32_CHARACTERS.iter().position(|x| *x as char == n) {
+        Some(d) => Ok(d as u8),
+        None => Err(Error::InvalidCharacter(c)),
+    }
+}
+
+fn checksum(version: u8, data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(
+        Sha256::new()
+            .chain_update([version])
+            .chain_update(data)
+            .finalize(),
+    );
+    let mut c = [0u8; 4];
+    c.copy_from_slice(&hash[..4]);
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn encode_decode() {
+        let vectors = [
+            ("", ""),
+            ("00", "0"),
+            ("0000", "00"),
+            ("01", "1"),
+            ("22", "12"),
+            ("0001", "01"),
+            (
+                "a46ff88886c2ef9762d970b4d2c63678835bd39d",
+                "MHQZH246RBQSERPSE2TD5HHPF21NQMWX",
+            ),
+        ];
+
+        for (hex, c32) in vectors {
+            let bytes = hex::decode(hex).unwrap();
+            assert_eq!(encode(&bytes), c32);
+            assert_eq!(decode(c32).unwrap(), bytes);
+        }
+
+        assert_eq!(
+            decode("mhqzh246rbqserpse2td5hhpf21nqmwx").unwrap(),
+            decode("MHQZH246RBQSERPSE2TD5HHPF21NQMWX").unwrap()
+        );
+        assert_eq!(decode("oIl").unwrap(), decode("011").unwrap());
+        assert_eq!(decode("U"), Err(Error::InvalidCharacter('U')));
+    }
+
+    #[test]
+    fn check() {
+        let hash: [u8; 20] = hex::decode("a46ff88886c2ef9762d970b4d2c63678835bd39d")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(
+            check_encode(MAINNET_SINGLESIG, &hash).unwrap(),
+            "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7"
+        );
+        assert_eq!(
+            address(MAINNET_SINGLESIG, &hash).unwrap(),
+            "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7"
+        );
+        assert_eq!(
+            address_decode("SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7").unwrap(),
+            (MAINNET_SINGLESIG, hash)
+        );
+
+        for version in [MAINNET_MULTISIG, TESTNET_SINGLESIG, TESTNET_MULTISIG, 0, 31] {
+            let s = address(version, &hash).unwrap();
+            assert_eq!(address_decode(&s).unwrap(), (version, hash));
+        }
+
+        assert_eq!(
+            address_decode("SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ8"),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            address_decode("XP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7"),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(address(32, &hash), Err(Error::InvalidVersion(32)));
+        assert_eq!(check_decode("P"), Err(Error::TooShort));
+    }
+
+    #[test]
+    fn keys() {
+        let keys: Vec<PublicKey> = (1..=3)
+            .map(|i| PublicKey::new(&Scalar::from(i)).unwrap())
+            .collect();
+
+        let single = stacks_address(&keys[0], TESTNET_SINGLESIG).unwrap();
+        assert!(single.starts_with("ST"));
+        assert_eq!(
+            address_decode(&single).unwrap(),
+            (TESTNET_SINGLESIG, keys[0].hash160())
+        );
+
+        let multi = stacks_multisig_address(2, &keys, MAINNET_MULTISIG).unwrap();
+        assert!(multi.starts_with("SM"));
+        assert_eq!(
+            address_decode(&multi).unwrap(),
+            (MAINNET_MULTISIG, multisig_hash(2, &keys).unwrap())
+        );
+        assert_ne!(multisig_hash(1, &keys), multisig_hash(2, &keys));
+
+        assert_eq!(multisig_hash(0, &keys), Err(Error::InvalidMultisig));
+        assert_eq!(multisig_hash(4, &keys), Err(Error::InvalidMultisig));
+
+        // vectors from the Stacks Foundation's stacks-core crate, which were generated with blockstack_lib
+        let vectors = [
+            (
+                1,
+                &["028cac21ac93bf697dc31da79e11aad8d285b2e2e81bcfc8de982179c6d468d339"][..],
+                "fc1058076c56333d7d2d9fbb936aefa632c0e7a8",
+            ),
+            (
+                2,
+                &[
+                    "0325a1b9799db9852ee1c99280b20695b1889eff7ec0352d634912818d02f91f84",
+                    "0279d7abd36d41d51e225efbbc8376a257051cecdf8b47eaffeb49b77547bc3bff",
+                ][..],
+                "073503b6e6ef916e4ab40f31abc83217c271d917",
+            ),
+        ];
+
+        for (threshold, hexes, hash) in vectors {
+            let keys: Vec<PublicKey> = hexes
+                .iter()
+                .map(|h| PublicKey::try_from(&hex::decode(h).unwrap()[..]).unwrap())
+                .collect();
+
+            assert_eq!(hex::encode(multisig_hash(threshold, &keys).unwrap()), hash);
+        }
+
+        let keys: Vec<PublicKey> = vectors[1]
+            .1
+            .iter()
+            .map(|h| PublicKey::try_from(&hex::decode(h).unwrap()[..]).unwrap())
+            .collect();
+        assert_eq!(
+            stacks_multisig_address(2, &keys, MAINNET_MULTISIG).unwrap(),
+            "SM3KA0XPWVQS2VJAPG7K3AY868BW4WES2YAAS5VK"
+        );
+        assert_eq!(
+            stacks_multisig_address(2, &keys, TESTNET_MULTISIG).unwrap(),
+            "SN3KA0XPWVQS2VJAPG7K3AY868BW4WES2ZNB07WN"
+        );
+    }
+}
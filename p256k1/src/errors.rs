@@ -6,11 +6,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// Base58-related errors
+#[non_exhaustive]
 pub enum Base58Error {
     /// Error decoding
     Decode,
     /// Error encoding
     Encode,
+    /// Error verifying a Base58Check checksum
+    Checksum,
 }
 
 impl Display for Base58Error {
@@ -21,6 +24,7 @@ impl Display for Base58Error {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 /// Errors when performing conversion operations
+#[non_exhaustive]
 pub enum ConversionError {
     /// Error decompressing a point into a field element
     BadFieldElement,
@@ -30,6 +34,8 @@ pub enum ConversionError {
     WrongNumberOfBytes(usize),
     /// Error converting a base58-related value
     Base58(Base58Error),
+    /// Error decoding a hex string
+    Hex,
 }
 
 impl Display for ConversionError {
@@ -0,0 +1,190 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use sha3::{Digest, Keccak256};
+use std::str::FromStr;
+
+use crate::{errors::ConversionError, keys::PublicKey};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors in Ethereum address parsing
+pub enum Error {
+    /// The address was mixed case, but not the EIP-55 checksum case
+    InvalidChecksum,
+    /// Error converting the address from hex
+    Conversion(ConversionError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An Ethereum address, which is the last 20 bytes of the keccak256 of an uncompressed public key
+pub struct Address {
+    /// The raw bytes of the address
+    pub data: [u8; 20],
+}
+
+impl Address {
+    /// Return the address of the public key
+    pub fn new(public_key: &PublicKey) -> Self {
+        let hash = Keccak256::digest(public_key.to_xy_bytes());
+        let mut data = [0u8; 20];
+        data.copy_from_slice(&hash[12..]);
+
+        Self { data }
+    }
+
+    /// Return a byte slice of the address
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Return the address as 0x followed by 40 hex digits, each letter of which is uppercased if the matching nibble of the keccak256 of the lowercase hex is 8 or more, as EIP-55 specifies
+    pub fn to_checksum(&self) -> String {
+        let lower = hex::encode(self.data);
+        let hash = Keccak256::digest(lower.as_bytes());
+
+        let mut s = String::with_capacity(42);
+        s.push_str("0x");
+        for (i, c) in lower.chars().enumerate() {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xF;
+            if nibble >= 8 {
+                s.push(c.to_ascii_uppercase());
+            } else {
+                s.push(c);
+            }
+        }
+
+        s
+    }
+
+    /// Return true if s is this address with exactly the EIP-55 checksum case
+    pub fn is_valid_checksum(s: &str) -> bool {
+        match Self::try_from(s) {
+            Ok(a) => a.to_checksum() == s || a.to_checksum()[2..] == *s,
+            Err(_) => false,
+        }
+    }
+}
+
+impl Display for Address {
+    /// Write the EIP-55 checksummed address
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_checksum())
+    }
+}
+
+impl From<&PublicKey> for Address {
+    fn from(public_key: &PublicKey) -> Self {
+        Self::new(public_key)
+    }
+}
+
+impl From<[u8; 20]> for Address {
+    fn from(data: [u8; 20]) -> Self {
+        Self { data }
+    }
+}
+
+impl TryFrom<&str> for Address {
+    type Error = Error;
+
+    /// Parse 40 hex digits with an optional 0x prefix; all lowercase and all uppercase addresses carry no checksum, but mixed case ones must match EIP-55
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+
+        let bytes = match hex::decode(digits) {
+            Ok(bytes) => bytes,
+            Err(_e) => return Err(Error::Conversion(ConversionError::Hex)),
+        };
+        let data: [u8; 20] = match bytes.try_into() {
+            Ok(data) => data,
+            Err(bytes) => {
+                return Err(Error::Conversion(ConversionError::WrongNumberOfBytes(
+                    bytes.len(),
+                )))
+            }
+        };
+        let address = Self { data };
+
+        let has_lower = digits.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = digits.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper && address.to_checksum()[2..] != *digits {
+            return Err(Error::InvalidChecksum);
+        }
+
+        Ok(address)
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    /// Parse an address as TryFrom does
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Address::try_from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn eip55() {
+        let vectors = [
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x27b1fdb04752bbc536007a920d24acb045561c26",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+
+        for s in vectors {
+            let a = Address::try_from(s).unwrap();
+            assert_eq!(a.to_checksum(), s);
+            assert!(Address::is_valid_checksum(s));
+            assert_eq!(Address::from_str(&s.to_lowercase()).unwrap(), a);
+            assert_eq!(Address::try_from(&s[2..]).unwrap(), a);
+        }
+
+        // flip the case of one letter
+        assert_eq!(
+            Address::try_from("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(Error::InvalidChecksum)
+        );
+        assert!(!Address::is_valid_checksum(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        ));
+        assert_eq!(
+            Address::try_from("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+            Err(Error::Conversion(ConversionError::WrongNumberOfBytes(19)))
+        );
+        assert_eq!(
+            Address::try_from("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg"),
+            Err(Error::Conversion(ConversionError::Hex))
+        );
+    }
+
+    #[test]
+    fn from_public_key() {
+        let vectors = [
+            (1, "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"),
+            (2, "0x2B5AD5c4795c026514f8317c7a215E218DcCD6cF"),
+        ];
+
+        for (k, s) in vectors {
+            let public_key = PublicKey::new(&Scalar::from(k)).unwrap();
+            assert_eq!(public_key.eth_address().to_string(), s);
+            assert_eq!(Address::from(&public_key), Address::try_from(s).unwrap());
+        }
+    }
+}
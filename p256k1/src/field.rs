@@ -6,13 +6,11 @@ use core::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     ops::{Add, AddAssign, BitXor, Mul, MulAssign, Neg, Sub},
+    str::FromStr,
 };
 use num_traits::{One, Zero};
 use rand_core::{CryptoRng, RngCore};
-use serde::{
-    de::{self, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp::Ordering;
 
 use crate::_rename::{
@@ -25,6 +23,7 @@ use crate::bindings::secp256k1_fe;
 use crate::errors::{Base58Error, ConversionError};
 
 use crate::scalar::Scalar;
+use crate::traits::{self, FixedBytes, StringFormats};
 
 /// Field size
 pub const P: [u8; 32] = [
@@ -47,6 +46,12 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<ConversionError> for Error {
+    fn from(e: ConversionError) -> Self {
+        Error::Conversion(e)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 /**
 Element is a wrapper around libsecp256k1's internal secp256k1_fe struct.  It provides a field element, which is like a scalar but not necessarily reduced modulo the group order
@@ -73,6 +78,17 @@ impl Element {
         Self::from(bytes)
     }
 
+    /// Construct a field element from big endian bytes, failing if they encode a value not less than the field size
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let mut s = Element::new();
+
+        if unsafe { secp256k1_fe_set_b32(&mut s.fe, bytes.as_ptr()) } == 0 {
+            return Err(Error::Conversion(ConversionError::BadFieldElement));
+        }
+
+        Ok(s)
+    }
+
     /// Set the fe to the passed integer
     pub fn set_int(&mut self, i: i32) {
         unsafe {
@@ -107,6 +123,18 @@ impl Element {
         unsafe { secp256k1_fe_is_odd(&self.fe) == 1 }
     }
 
+    /// Return a square root of this fe, or None if it is not a square
+    pub fn sqrt(&self) -> Option<Element> {
+        // p = 3 mod 4, so the square root, if it exists, is c^((p+1)/4), which is 4^-1 mod p
+        let y = self ^ Element::from(4).invert();
+
+        if y * y == *self {
+            Some(y)
+        } else {
+            None
+        }
+    }
+
     /// Fast exponentiation using the square and multiply algorithm
     pub fn square_and_multiply(x: &Self, n: &Self) -> Self {
         let mut r = Self::one();
@@ -190,46 +218,34 @@ impl Ord for Element {
     }
 }
 
-impl Serialize for Element {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(&self.to_bytes())
-    }
-}
+/// Field elements are encoded as 32 big-endian bytes
+impl StringFormats for Element {
+    type Bytes = [u8; 32];
+    type Error = Error;
 
-struct ElementVisitor;
+    const DESCRIPTION: &'static str = "a field element for the secp256k1 curve";
 
-impl<'de> Visitor<'de> for ElementVisitor {
-    type Value = Element;
+    fn encode_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter
-            .write_str("an array of bytes which represents field element for the secp256k1 curve")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Element::try_from(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match Self::Value::try_from(value) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<32> for Element {
+    fn to_fixed_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for Element {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -238,7 +254,7 @@ impl<'de> Deserialize<'de> for Element {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(ElementVisitor)
+        traits::deserialize(deserializer)
     }
 }
 
@@ -266,6 +282,7 @@ impl From<i32> for Element {
 }
 
 impl From<[u8; 32]> for Element {
+    /// Construct a field element from big endian bytes without checking that they are less than the field size
     fn from(bytes: [u8; 32]) -> Self {
         let mut s = Element::new();
         let p: *const u8 = bytes.as_ptr();
@@ -286,7 +303,7 @@ impl TryFrom<&[u8]> for Element {
                 let mut data = [0u8; 32];
 
                 data.clone_from_slice(bytes);
-                Ok(Element::from(data))
+                Element::from_canonical_bytes(&data)
             }
             n => Err(Error::Conversion(ConversionError::WrongNumberOfBytes(n))),
         }
@@ -305,6 +322,15 @@ impl TryFrom<&str> for Element {
     }
 }
 
+impl FromStr for Element {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Element::try_from(s)
+    }
+}
+
 impl From<Element> for String {
     fn from(s: Element) -> String {
         bs58::encode(s.to_bytes()).into_string()
@@ -709,6 +735,23 @@ mod tests {
         assert_eq!(s, t);
     }
 
+    #[test]
+    fn canonical() {
+        let mut p_minus_one = P;
+        p_minus_one[31] -= 1;
+
+        let x = Element::from_canonical_bytes(&p_minus_one).unwrap();
+        assert_eq!(x, -Element::one());
+        assert!(Element::from_canonical_bytes(&P).is_err());
+        assert!(Element::from_canonical_bytes(&[0xFF; 32]).is_err());
+
+        assert!(Element::try_from(&P[..]).is_err());
+        assert!(Element::try_from(bs58::encode(P).into_string().as_str()).is_err());
+
+        let s = serde_json::to_string(&P).expect("failed to serialize");
+        assert!(serde_json::from_str::<Element>(&s).is_err());
+    }
+
     #[test]
     fn custom_serde() {
         let mut rng = OsRng::default();
@@ -718,4 +761,19 @@ mod tests {
 
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn sqrt() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0x40 {
+            let x = Element::random(&mut rng);
+            let y = (x * x).sqrt().unwrap();
+            assert!(y == x || y == -x);
+        }
+
+        // p = 3 mod 4, so -1 is not a square
+        assert!((-Element::one()).sqrt().is_none());
+        assert_eq!(Element::zero().sqrt(), Some(Element::zero()));
+    }
 }
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::{hash_to_curve, point::Point};
+
+type Cache = Mutex<HashMap<(Vec<u8>, u32), Point>>;
+
+static CACHE: OnceLock<Cache> = OnceLock::new();
+
+/**
+Return the nothing-up-my-sleeve point for the passed domain and index.
+
+The point is `hash_to_curve(I2OSP(index, 4), domain)` using the RFC 9380 `secp256k1_XMD:SHA-256_SSWU_RO_` suite, so its discrete log relative to `point::G` (or any other such point) is unknown.  Results are cached for the lifetime of the process, keyed by (domain, index), so repeated calls and repeated Generators for the same label only hash to the curve once.  The point is returned in canonical form, so later compression, hashing and ordering are cheap.
+*/
+pub fn nums(domain: &[u8], index: u32) -> Point {
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let key = (domain.to_vec(), index);
+
+    if let Some(p) = cache.lock().unwrap().get(&key) {
+        return *p;
+    }
+
+    // hash without holding the lock, so other threads aren't blocked; a race only computes the same point twice
+    let p = hash_to_curve::hash_to_curve(&index.to_be_bytes(), domain).canonical();
+    cache.lock().unwrap().insert(key, p);
+
+    p
+}
+
+/**
+Generators is a deterministic set of independent nothing-up-my-sleeve points, suitable for Pedersen and vector commitments.
+
+For a given label, `h` is `nums(label || "/h", 0)`, `g_vec[i]` is `nums(label || "/G", i)` and `h_vec[i]` is `nums(label || "/H", i)`.  The first n generators of each vector therefore do not depend on n.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators {
+    /// The blinding generator H
+    pub h: Point,
+    /// The vector of generators G_i
+    pub g_vec: Vec<Point>,
+    /// The vector of generators H_i
+    pub h_vec: Vec<Point>,
+}
+
+impl Generators {
+    /// Derive a single blinding generator and n generators in each vector for the passed label
+    pub fn new(label: &[u8], n: u32) -> Self {
+        let domain = |suffix: &[u8]| [label, suffix].concat();
+        let h_domain = domain(b"/h");
+        let g_domain = domain(b"/G");
+        let hs_domain = domain(b"/H");
+
+        Self {
+            h: nums(&h_domain, 0),
+            g_vec: (0..n).map(|i| nums(&g_domain, i)).collect(),
+            h_vec: (0..n).map(|i| nums(&hs_domain, i)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::G;
+    use std::collections::HashSet;
+
+    #[test]
+    fn nums_deterministic() {
+        let p = nums(b"p256k1 test", 7);
+        let q = nums(b"p256k1 test", 7);
+
+        assert_eq!(p, q);
+        assert!(CACHE
+            .get()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .contains_key(&(b"p256k1 test".to_vec(), 7)));
+        assert!(p.is_on_curve());
+        assert!(p.is_normalized());
+        assert_eq!(
+            p,
+            hash_to_curve::hash_to_curve(&7u32.to_be_bytes(), b"p256k1 test")
+        );
+        assert_ne!(p, nums(b"p256k1 test", 8));
+        assert_ne!(p, nums(b"p256k1 other", 7));
+        assert_ne!(p, G);
+    }
+
+    #[test]
+    fn generators() {
+        let n = 16;
+        let gens = Generators::new(b"p256k1 test", n);
+
+        assert_eq!(gens.g_vec.len(), n as usize);
+        assert_eq!(gens.h_vec.len(), n as usize);
+
+        let mut all = vec![G, gens.h];
+        all.extend(gens.g_vec.iter());
+        all.extend(gens.h_vec.iter());
+
+        let set: HashSet<Point> = all.iter().cloned().collect();
+        assert_eq!(set.len(), all.len());
+
+        let smaller = Generators::new(b"p256k1 test", n / 2);
+        assert_eq!(smaller.h, gens.h);
+        assert_eq!(smaller.g_vec[..], gens.g_vec[..(n / 2) as usize]);
+        assert_eq!(smaller.h_vec[..], gens.h_vec[..(n / 2) as usize]);
+
+        assert_eq!(gens, Generators::new(b"p256k1 test", n));
+        assert_ne!(gens, Generators::new(b"p256k1 other", n));
+    }
+}
@@ -26,3 +26,48 @@ pub fn secp256k1_ge_set_gej(r: &mut secp256k1_ge, a: &secp256k1_gej) {
     r.x = ax;
     r.y = ay;
 }
+
+/// Load the 64 byte internal representation of a secp256k1_pubkey or secp256k1_xonly_pubkey, which on 64 bit targets is a secp256k1_ge_storage of 4x64 bit limbs for each coordinate
+#[inline]
+pub fn secp256k1_pubkey_load(r: &mut secp256k1_ge, data: &[u8; 64]) {
+    r.x = fe_from_storage(&data[..32]);
+    r.y = fe_from_storage(&data[32..]);
+    r.infinity = 0;
+}
+
+/// Save an affine point with normalized coordinates into the 64 byte internal representation of a secp256k1_pubkey or secp256k1_xonly_pubkey
+#[inline]
+pub fn secp256k1_pubkey_save(data: &mut [u8; 64], a: &secp256k1_ge) {
+    fe_to_storage(&mut data[..32], &a.x);
+    fe_to_storage(&mut data[32..], &a.y);
+}
+
+fn fe_from_storage(bytes: &[u8]) -> secp256k1_fe {
+    const M: u64 = 0xFFFFFFFFFFFFF;
+    let mut a = [0u64; 4];
+    for (i, limb) in a.iter_mut().enumerate() {
+        *limb = u64::from_ne_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+    }
+
+    secp256k1_fe {
+        n: [
+            a[0] & M,
+            a[0] >> 52 | ((a[1] << 12) & M),
+            a[1] >> 40 | ((a[2] << 24) & M),
+            a[2] >> 28 | ((a[3] << 36) & M),
+            a[3] >> 16,
+        ],
+    }
+}
+
+fn fe_to_storage(bytes: &mut [u8], a: &secp256k1_fe) {
+    let r = [
+        a.n[0] | a.n[1] << 52,
+        a.n[1] >> 12 | a.n[2] << 40,
+        a.n[2] >> 24 | a.n[3] << 28,
+        a.n[3] >> 36 | a.n[4] << 16,
+    ];
+    for (i, limb) in r.iter().enumerate() {
+        bytes[i * 8..(i + 1) * 8].copy_from_slice(&limb.to_ne_bytes());
+    }
+}
@@ -0,0 +1,455 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use num_traits::{One, Zero};
+use primitive_types::U512;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{field, point::Point};
+
+/// The suite identifier for the random oracle encoding, which is also the conventional DST suffix
+pub const SUITE_RO: &str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// The suite identifier for the nonuniform encoding, which is also the conventional DST suffix
+pub const SUITE_NU: &str = "secp256k1_XMD:SHA-256_SSWU_NU_";
+
+/// The number of bytes hashed into each field element, ceil((ceil(log2(p)) + k) / 8) with k = 128
+const L: usize = 48;
+
+/// The output size of SHA-256 in bytes
+const B_IN_BYTES: usize = 32;
+
+/// The input block size of SHA-256 in bytes
+const S_IN_BYTES: usize = 64;
+
+/// Prefix used to hash domain separation tags which are longer than 255 bytes
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// The A' coefficient of the curve E': y^2 = x^3 + A'x + B', which is 3-isogenous to secp256k1
+const ISO_A: [u8; 32] = [
+    0x3F, 0x87, 0x31, 0xAB, 0xDD, 0x66, 0x1A, 0xDC, 0xA0, 0x8A, 0x55, 0x58, 0xF0, 0xF5, 0xD2, 0x72,
+    0xE9, 0x53, 0xD3, 0x63, 0xCB, 0x6F, 0x0E, 0x5D, 0x40, 0x54, 0x47, 0xC0, 0x1A, 0x44, 0x45, 0x33,
+];
+
+/// The B' coefficient of the isogenous curve E'
+const ISO_B: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xEB,
+];
+
+/// Coefficients k_(1,0)..k_(1,3) of the isogeny map x numerator, lowest degree first
+const X_NUM: [[u8; 32]; 4] = [
+    [
+        0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3,
+        0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8D, 0xAA, 0xAA,
+        0xA8, 0xC7,
+    ],
+    [
+        0x07, 0xD3, 0xD4, 0xC8, 0x0B, 0xC3, 0x21, 0xD5, 0xB9, 0xF3, 0x15, 0xCE, 0xA7, 0xFD, 0x44,
+        0xC5, 0xD5, 0x95, 0xD2, 0xFC, 0x0B, 0xF6, 0x3B, 0x92, 0xDF, 0xFF, 0x10, 0x44, 0xF1, 0x7C,
+        0x65, 0x81,
+    ],
+    [
+        0x53, 0x4C, 0x32, 0x8D, 0x23, 0xF2, 0x34, 0xE6, 0xE2, 0xA4, 0x13, 0xDE, 0xCA, 0x25, 0xCA,
+        0xEC, 0xE4, 0x50, 0x61, 0x44, 0x03, 0x7C, 0x40, 0x31, 0x4E, 0xCB, 0xD0, 0xB5, 0x3D, 0x9D,
+        0xD2, 0x62,
+    ],
+    [
+        0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3,
+        0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8E, 0x38, 0xE3, 0x8D, 0xAA, 0xAA,
+        0xA8, 0x8C,
+    ],
+];
+
+/// Coefficients k_(2,0)..k_(2,1) of the monic isogeny map x denominator, lowest degree first
+const X_DEN: [[u8; 32]; 2] = [
+    [
+        0xD3, 0x57, 0x71, 0x19, 0x3D, 0x94, 0x91, 0x8A, 0x9C, 0xA3, 0x4C, 0xCB, 0xB7, 0xB6, 0x40,
+        0xDD, 0x86, 0xCD, 0x40, 0x95, 0x42, 0xF8, 0x48, 0x7D, 0x9F, 0xE6, 0xB7, 0x45, 0x78, 0x1E,
+        0xB4, 0x9B,
+    ],
+    [
+        0xED, 0xAD, 0xC6, 0xF6, 0x43, 0x83, 0xDC, 0x1D, 0xF7, 0xC4, 0xB2, 0xD5, 0x1B, 0x54, 0x22,
+        0x54, 0x06, 0xD3, 0x6B, 0x64, 0x1F, 0x5E, 0x41, 0xBB, 0xC5, 0x2A, 0x56, 0x61, 0x2A, 0x8C,
+        0x6D, 0x14,
+    ],
+];
+
+/// Coefficients k_(3,0)..k_(3,3) of the isogeny map y numerator, lowest degree first
+const Y_NUM: [[u8; 32]; 4] = [
+    [
+        0x4B, 0xDA, 0x12, 0xF6, 0x84, 0xBD, 0xA1, 0x2F, 0x68, 0x4B, 0xDA, 0x12, 0xF6, 0x84, 0xBD,
+        0xA1, 0x2F, 0x68, 0x4B, 0xDA, 0x12, 0xF6, 0x84, 0xBD, 0xA1, 0x2F, 0x68, 0x4B, 0x8E, 0x38,
+        0xE2, 0x3C,
+    ],
+    [
+        0xC7, 0x5E, 0x0C, 0x32, 0xD5, 0xCB, 0x7C, 0x0F, 0xA9, 0xD0, 0xA5, 0x4B, 0x12, 0xA0, 0xA6,
+        0xD5, 0x64, 0x7A, 0xB0, 0x46, 0xD6, 0x86, 0xDA, 0x6F, 0xDF, 0xFC, 0x90, 0xFC, 0x20, 0x1D,
+        0x71, 0xA3,
+    ],
+    [
+        0x29, 0xA6, 0x19, 0x46, 0x91, 0xF9, 0x1A, 0x73, 0x71, 0x52, 0x09, 0xEF, 0x65, 0x12, 0xE5,
+        0x76, 0x72, 0x28, 0x30, 0xA2, 0x01, 0xBE, 0x20, 0x18, 0xA7, 0x65, 0xE8, 0x5A, 0x9E, 0xCE,
+        0xE9, 0x31,
+    ],
+    [
+        0x2F, 0x68, 0x4B, 0xDA, 0x12, 0xF6, 0x84, 0xBD, 0xA1, 0x2F, 0x68, 0x4B, 0xDA, 0x12, 0xF6,
+        0x84, 0xBD, 0xA1, 0x2F, 0x68, 0x4B, 0xDA, 0x12, 0xF6, 0x84, 0xBD, 0xA1, 0x2F, 0x38, 0xE3,
+        0x8D, 0x84,
+    ],
+];
+
+/// Coefficients k_(4,0)..k_(4,2) of the monic isogeny map y denominator, lowest degree first
+const Y_DEN: [[u8; 32]; 3] = [
+    [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF,
+        0xF9, 0x3B,
+    ],
+    [
+        0x7A, 0x06, 0x53, 0x4B, 0xB8, 0xBD, 0xB4, 0x9F, 0xD5, 0xE9, 0xE6, 0x63, 0x27, 0x22, 0xC2,
+        0x98, 0x94, 0x67, 0xC1, 0xBF, 0xC8, 0xE8, 0xD9, 0x78, 0xDF, 0xB4, 0x25, 0xD2, 0x68, 0x5C,
+        0x25, 0x73,
+    ],
+    [
+        0x64, 0x84, 0xAA, 0x71, 0x65, 0x45, 0xCA, 0x2C, 0xF3, 0xA7, 0x0C, 0x3F, 0xA8, 0xFE, 0x33,
+        0x7E, 0x0A, 0x3D, 0x21, 0x16, 0x2F, 0x0D, 0x62, 0x99, 0xA7, 0xBF, 0x81, 0x92, 0xBF, 0xD2,
+        0xA7, 0x6F,
+    ],
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Errors in hash to curve operations
+pub enum Error {
+    /// The requested output length is too large for expand_message_xmd
+    InvalidLength(usize),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Expand msg into len pseudorandom bytes using SHA-256, as specified in RFC 9380 section 5.3.1
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+    let ell = len.div_ceil(B_IN_BYTES);
+    if ell > 255 || len > 65535 {
+        return Err(Error::InvalidLength(len));
+    }
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b0 = Sha256::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut bi = Sha256::new()
+        .chain_update(b0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&bi);
+
+    for i in 2..=ell {
+        let mut xor = [0u8; B_IN_BYTES];
+        for (j, x) in xor.iter_mut().enumerate() {
+            *x = b0[j] ^ bi[j];
+        }
+
+        bi = Sha256::new()
+            .chain_update(xor)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&bi);
+    }
+
+    uniform_bytes.truncate(len);
+
+    Ok(uniform_bytes)
+}
+
+/// Hash msg into count field elements, as specified in RFC 9380 section 5.2
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Result<Vec<field::Element>, Error> {
+    let uniform_bytes = expand_message_xmd(msg, dst, count * L)?;
+    let p = U512::from_big_endian(&field::P);
+
+    Ok(uniform_bytes
+        .chunks(L)
+        .map(|chunk| {
+            let mut bytes = [0u8; 64];
+            (U512::from_big_endian(chunk) % p).to_big_endian(&mut bytes);
+
+            let mut data = [0u8; 32];
+            data.copy_from_slice(&bytes[32..]);
+
+            field::Element::from(data)
+        })
+        .collect())
+}
+
+/// Map a field element to a point on secp256k1, using the simplified SWU map onto the isogenous curve E' followed by the 3-isogeny map
+pub fn map_to_curve(u: &field::Element) -> Point {
+    let (x, y) = map_to_curve_simple_swu(u);
+
+    iso_map(&x, &y)
+}
+
+/// Hash msg to a point using the secp256k1_XMD:SHA-256_SSWU_RO_ suite, which is indistinguishable from a random oracle
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Point {
+    // the expect should not fail because the output length is fixed and well within the limits of expand_message_xmd
+    let u = hash_to_field(msg, dst, 2).expect("hash_to_field failed");
+
+    map_to_curve(&u[0]) + map_to_curve(&u[1])
+}
+
+/// Hash msg to a point using the secp256k1_XMD:SHA-256_SSWU_NU_ suite, which is faster but whose output is not uniformly distributed
+pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Point {
+    // the expect should not fail because the output length is fixed and well within the limits of expand_message_xmd
+    let u = hash_to_field(msg, dst, 1).expect("hash_to_field failed");
+
+    map_to_curve(&u[0])
+}
+
+fn map_to_curve_simple_swu(u: &field::Element) -> (field::Element, field::Element) {
+    let a = field::Element::from(ISO_A);
+    let b = field::Element::from(ISO_B);
+    let z = -field::Element::from(11);
+
+    let zu2 = z * *u * *u;
+    let tv1 = zu2 * zu2 + zu2;
+
+    let x1 = if tv1.is_zero() {
+        b * (z * a).invert()
+    } else {
+        -b * a.invert() * (field::Element::one() + tv1.invert())
+    };
+    let gx1 = x1 * x1 * x1 + a * x1 + b;
+
+    let (x, y) = match gx1.sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = zu2 * x1;
+            let gx2 = x2 * x2 * x2 + a * x2 + b;
+            // when gx1 is not square then gx2 = Z^3 u^6 gx1 must be, since Z is not square
+            (x2, gx2.sqrt().expect("gx2 must be square when gx1 is not"))
+        }
+    };
+
+    if u.normalize().is_odd() != y.is_odd() {
+        (x, -y)
+    } else {
+        (x, y)
+    }
+}
+
+fn iso_map(x: &field::Element, y: &field::Element) -> Point {
+    let x_num = horner(&X_NUM, x, false);
+    let x_den = horner(&X_DEN, x, true);
+    let y_num = horner(&Y_NUM, x, false);
+    let y_den = horner(&Y_DEN, x, true);
+
+    if x_den.is_zero() || y_den.is_zero() {
+        return Point::identity();
+    }
+
+    Point::from_affine_unchecked(&(x_num * x_den.invert()), &(*y * y_num * y_den.invert()))
+}
+
+fn horner(coefficients: &[[u8; 32]], x: &field::Element, monic: bool) -> field::Element {
+    let init = if monic {
+        field::Element::one()
+    } else {
+        field::Element::zero()
+    };
+
+    coefficients
+        .iter()
+        .rev()
+        .fold(init, |acc, k| acc * *x + field::Element::from(*k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(s: &str) -> field::Element {
+        field::Element::try_from(&hex::decode(s).unwrap()[..]).unwrap()
+    }
+
+    #[test]
+    fn expand_message() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&str, usize, &str); 10] = [
+            ("", 32, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            ("", 128, "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"),
+            ("abc", 32, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            ("abc", 128, "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40"),
+            ("abcdef0123456789", 32, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+            ("abcdef0123456789", 128, "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df"),
+            (&format!("q128_{}", "q".repeat(128)), 32, "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9"),
+            (&format!("q128_{}", "q".repeat(128)), 128, "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a"),
+            (&format!("a512_{}", "a".repeat(512)), 32, "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c"),
+            (&format!("a512_{}", "a".repeat(512)), 128, "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487"),
+        ];
+
+        for (msg, len, expected) in vectors {
+            let uniform_bytes = expand_message_xmd(msg.as_bytes(), dst, len).unwrap();
+            assert_eq!(hex::encode(uniform_bytes), expected);
+        }
+
+        assert!(expand_message_xmd(b"", dst, 255 * 32 + 1).is_err());
+    }
+
+    #[test]
+    fn oversize_dst() {
+        let dst = [0x5Au8; 256];
+        let prefixed = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+
+        assert_eq!(
+            expand_message_xmd(b"abc", &dst, 64).unwrap(),
+            expand_message_xmd(b"abc", &prefixed, 64).unwrap()
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_ro() {
+        let dst = format!("QUUX-V01-CS02-with-{SUITE_RO}");
+        let vectors = [
+            (
+                "",
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                [
+                    "6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3",
+                    "1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16",
+                ],
+            ),
+            (
+                "abc",
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                [
+                    "128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61",
+                    "5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00",
+                ],
+            ),
+            (
+                "abcdef0123456789",
+                "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+                [
+                    "ea67a7c02f2cd5d8b87715c169d055a22520f74daeb080e6180958380e2f98b9",
+                    "7434d0d1a500d38380d1f9615c021857ac8d546925f5f2355319d823a478da18",
+                ],
+            ),
+            (
+                &format!("q128_{}", "q".repeat(128)),
+                "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+                "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+                [
+                    "eda89a5024fac0a8207a87e8cc4e85aa3bce10745d501a30deb87341b05bcdf5",
+                    "dfe78cd116818fc2c16f3837fedbe2639fab012c407eac9dfe9245bf650ac51d",
+                ],
+            ),
+            (
+                &format!("a512_{}", "a".repeat(512)),
+                "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+                "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+                [
+                    "8d862e7e7e23d7843fe16d811d46d7e6480127a6b78838c277bca17df6900e9f",
+                    "68071d2530f040f081ba818d3c7188a94c900586761e9115efa47ae9bd847938",
+                ],
+            ),
+        ];
+
+        for (msg, x, y, u) in vectors {
+            let us = hash_to_field(msg.as_bytes(), dst.as_bytes(), 2).unwrap();
+            assert_eq!(us[0], element(u[0]));
+            assert_eq!(us[1], element(u[1]));
+
+            let p = Point::hash_to_curve(msg.as_bytes(), dst.as_bytes());
+            assert!(p.is_on_curve());
+            assert_eq!(p.x(), element(x));
+            assert_eq!(p.y(), element(y));
+        }
+    }
+
+    #[test]
+    fn encode_to_curve_nu() {
+        let dst = format!("QUUX-V01-CS02-with-{SUITE_NU}");
+        let vectors = [
+            (
+                "",
+                "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+                "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+                ["0137fcd23bc3da962e8808f97474d097a6c8aa2881fceef4514173635872cf3b"],
+            ),
+            (
+                "abc",
+                "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+                "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+                ["e03f894b4d7caf1a50d6aa45cac27412c8867a25489e32c5ddeb503229f63a2e"],
+            ),
+            (
+                "abcdef0123456789",
+                "07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+                "c79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+                ["e7a6525ae7069ff43498f7f508b41c57f80563c1fe4283510b322446f32af41b"],
+            ),
+            (
+                &format!("q128_{}", "q".repeat(128)),
+                "b734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+                "03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+                ["d97cf3d176a2f26b9614a704d7d434739d194226a706c886c5c3c39806bc323c"],
+            ),
+            (
+                &format!("a512_{}", "a".repeat(512)),
+                "17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+                "e9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+                ["a9ffbeee1d6e41ac33c248fb3364612ff591b502386c1bf6ac4aaf1ea51f8c3b"],
+            ),
+        ];
+
+        for (msg, x, y, u) in vectors {
+            let us = hash_to_field(msg.as_bytes(), dst.as_bytes(), 1).unwrap();
+            assert_eq!(us[0], element(u[0]));
+
+            let p = Point::encode_to_curve(msg.as_bytes(), dst.as_bytes());
+            assert!(p.is_on_curve());
+            assert_eq!(p.x(), element(x));
+            assert_eq!(p.y(), element(y));
+        }
+    }
+
+    #[test]
+    fn map_to_curve_exceptional() {
+        // u = 0 takes the tv1 == 0 branch of the SWU map
+        let p = map_to_curve(&field::Element::zero());
+        assert!(p.is_on_curve());
+    }
+}
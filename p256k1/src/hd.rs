@@ -0,0 +1,890 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use hmac::{Hmac, Mac};
+use num_traits::Zero;
+use sha2::Sha512;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    base58,
+    errors::ConversionError,
+    keys::{KeyPair, PublicKey},
+    network::Network,
+    point::{Point, N},
+    scalar::Scalar,
+};
+
+/// The offset of hardened child numbers
+pub const HARDENED: u32 = 0x80000000;
+
+/// The HMAC key used to derive master keys from seeds
+const MASTER_KEY: &[u8] = b"Bitcoin seed";
+
+/// The length of a serialized extended key, before Base58Check
+const SERIALIZED_LEN: usize = 78;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors in BIP32 key derivation and serialization
+pub enum Error {
+    /// The seed was not between 16 and 64 bytes long
+    InvalidSeedLength(usize),
+    /// The derived key was invalid, which happens with probability below 2^-127, so the next index should be used instead
+    InvalidChild,
+    /// Hardened children can only be derived from an extended private key
+    HardenedFromPublic,
+    /// The derivation path was malformed
+    InvalidPath(String),
+    /// The serialized key had an unknown version
+    UnknownVersion([u8; 4]),
+    /// The serialized key was internally inconsistent or held an invalid key
+    InvalidKey,
+    /// Error converting the serialized key
+    Conversion(ConversionError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// A BIP32 derivation path, stored as child numbers with hardened children offset by HARDENED
+pub struct DerivationPath {
+    /// The child numbers, from the master key down
+    pub path: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Return true if the path contains a hardened child, so it can't be derived from an extended public key
+    pub fn is_hardened(&self) -> bool {
+        self.path.iter().any(|i| *i >= HARDENED)
+    }
+}
+
+impl From<Vec<u32>> for DerivationPath {
+    fn from(path: Vec<u32>) -> Self {
+        Self { path }
+    }
+}
+
+impl TryFrom<&str> for DerivationPath {
+    type Error = Error;
+    /// Parse a path such as m/84'/0'/0'/0/1, where hardened children are marked with ', h or H
+    fn try_from(s: &str) -> Result<Self, Error> {
+        let mut parts = s.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(Error::InvalidPath(s.to_string()));
+        }
+
+        let mut path = Vec::new();
+        for part in parts {
+            let (digits, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                Some(digits) => (digits, true),
+                None => (part, false),
+            };
+
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(Error::InvalidPath(s.to_string()));
+            }
+
+            let index = match digits.parse::<u32>() {
+                Ok(i) if i < HARDENED => i,
+                _ => return Err(Error::InvalidPath(s.to_string())),
+            };
+
+            path.push(if hardened { index + HARDENED } else { index });
+        }
+
+        Ok(Self { path })
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "m")?;
+        for i in &self.path {
+            if *i >= HARDENED {
+                write!(f, "/{}'", i - HARDENED)?;
+            } else {
+                write!(f, "/{}", i)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+/// A BIP32 extended private key
+pub struct ExtendedPrivateKey {
+    /// The network this key is serialized for
+    pub network: Network,
+    /// The number of derivations from the master key
+    pub depth: u8,
+    /// The first 4 bytes of the parent key's identifier, zero for the master key
+    pub parent_fingerprint: [u8; 4],
+    /// The child number this key was derived with, zero for the master key
+    pub child_number: u32,
+    /// The chain code
+    chain_code: [u8; 32],
+    /// The secret key
+    private_key: Scalar,
+}
+
+impl ExtendedPrivateKey {
+    /// Derive the master key from a seed of 16 to 64 bytes
+    pub fn new_master(seed: &[u8], network: Network) -> Result<Self, Error> {
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(Error::InvalidSeedLength(seed.len()));
+        }
+
+        let (il, chain_code) = hmac_sha512(MASTER_KEY, &[seed]);
+        let private_key = parse_scalar(&il).ok_or(Error::InvalidChild)?;
+        if private_key.is_zero() {
+            return Err(Error::InvalidChild);
+        }
+
+        Ok(Self {
+            network,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Derive the child key with the passed index, which is hardened if it is at least HARDENED
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        let (il, chain_code) = if index >= HARDENED {
+            let mut k = self.private_key.to_bytes();
+            let r = hmac_sha512(&self.chain_code, &[&[0], &k, &index.to_be_bytes()]);
+            k.zeroize();
+            r
+        } else {
+            hmac_sha512(
+                &self.chain_code,
+                &[&self.public_key().to_bytes(), &index.to_be_bytes()],
+            )
+        };
+
+        let tweak = parse_scalar(&il).ok_or(Error::InvalidChild)?;
+        let private_key = tweak + self.private_key;
+        if private_key.is_zero() {
+            return Err(Error::InvalidChild);
+        }
+
+        Ok(Self {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(Error::InvalidChild)?,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Derive the descendant key at the passed path
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.path
+            .iter()
+            .try_fold(self.clone(), |key, i| key.derive_child(*i))
+    }
+
+    /// Return the secret key
+    pub fn private_key(&self) -> &Scalar {
+        &self.private_key
+    }
+
+    /// Return the chain code, which is as sensitive as the secret key since with any child secret key it reveals the parent's
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Return the public key for this key's secret key
+    pub fn public_key(&self) -> PublicKey {
+        // the secret key was checked to be nonzero when this key was constructed
+        PublicKey::new(&self.private_key).unwrap()
+    }
+
+    /// Return the keypair for this key's secret key
+    pub fn key_pair(&self) -> KeyPair {
+        // the secret key was checked to be nonzero when this key was constructed
+        KeyPair::new(&self.private_key).unwrap()
+    }
+
+    /// Return the extended public key with the same chain code and metadata
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::from(self)
+    }
+
+    /// Return the hash160 of the public key, which identifies this key
+    pub fn identifier(&self) -> [u8; 20] {
+        self.public_key().hash160()
+    }
+
+    /// Return the first 4 bytes of the identifier, which children record as their parent fingerprint
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.identifier())
+    }
+
+    /// Serialize the key into the 78 byte BIP32 format
+    pub fn to_bytes(&self) -> [u8; SERIALIZED_LEN] {
+        let mut key = [0u8; 33];
+        key[1..].copy_from_slice(&self.private_key.to_bytes());
+
+        let bytes = serialize(
+            self.network.private_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &key,
+        );
+        key.zeroize();
+        bytes
+    }
+}
+
+impl Debug for ExtendedPrivateKey {
+    /// Print the extended public key instead, so the secret key can't leak into logs
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ExtendedPrivateKey")
+            .field(
+                "extended_public_key",
+                &self.extended_public_key().to_string(),
+            )
+            .field("private_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Zeroize for ExtendedPrivateKey {
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ExtendedPrivateKey {}
+
+impl Display for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", base58::encode_check(&self.to_bytes()))
+    }
+}
+
+impl From<ExtendedPrivateKey> for String {
+    fn from(key: ExtendedPrivateKey) -> String {
+        key.to_string()
+    }
+}
+
+impl TryFrom<&[u8]> for ExtendedPrivateKey {
+    type Error = Error;
+    /// Parse a key from the 78 byte BIP32 format
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let (version, depth, parent_fingerprint, child_number, chain_code, key) =
+            deserialize(bytes)?;
+
+        let network = if version == Network::Mainnet.private_version() {
+            Network::Mainnet
+        } else if version == Network::Testnet.private_version() {
+            Network::Testnet
+        } else {
+            return Err(Error::UnknownVersion(version));
+        };
+
+        if key[0] != 0 {
+            return Err(Error::InvalidKey);
+        }
+
+        let mut k = [0u8; 32];
+        k.copy_from_slice(&key[1..]);
+        let private_key = parse_scalar(&k).ok_or(Error::InvalidKey)?;
+        k.zeroize();
+        if private_key.is_zero() {
+            return Err(Error::InvalidKey);
+        }
+
+        Ok(Self {
+            network,
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            private_key,
+        })
+    }
+}
+
+impl TryFrom<&str> for ExtendedPrivateKey {
+    type Error = Error;
+    /// Parse a key from its xprv or tprv Base58Check string
+    fn try_from(s: &str) -> Result<Self, Error> {
+        match base58::decode_check(s) {
+            Ok(bytes) => Self::try_from(&bytes[..]),
+            Err(e) => Err(Error::Conversion(ConversionError::Base58(e))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A BIP32 extended public key
+pub struct ExtendedPublicKey {
+    /// The network this key is serialized for
+    pub network: Network,
+    /// The number of derivations from the master key
+    pub depth: u8,
+    /// The first 4 bytes of the parent key's identifier, zero for the master key
+    pub parent_fingerprint: [u8; 4],
+    /// The child number this key was derived with, zero for the master key
+    pub child_number: u32,
+    /// The chain code
+    pub chain_code: [u8; 32],
+    /// The public key
+    pub public_key: PublicKey,
+}
+
+impl ExtendedPublicKey {
+    /// Derive the non-hardened child key with the passed index
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        if index >= HARDENED {
+            return Err(Error::HardenedFromPublic);
+        }
+
+        let (il, chain_code) = hmac_sha512(
+            &self.chain_code,
+            &[&self.public_key.to_bytes(), &index.to_be_bytes()],
+        );
+
+        let tweak = parse_scalar(&il).ok_or(Error::InvalidChild)?;
+        let public_key = self
+            .public_key
+            .tweak_add(&tweak)
+            .map_err(|_| Error::InvalidChild)?;
+
+        Ok(Self {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(Error::InvalidChild)?,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            public_key,
+        })
+    }
+
+    /// Derive the descendant key at the passed path, which must not contain hardened children
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.path
+            .iter()
+            .try_fold(self.clone(), |key, i| key.derive_child(*i))
+    }
+
+    /// Return the public key as a point
+    pub fn point(&self) -> Point {
+        Point::from(&self.public_key)
+    }
+
+    /// Return the hash160 of the public key, which identifies this key
+    pub fn identifier(&self) -> [u8; 20] {
+        self.public_key.hash160()
+    }
+
+    /// Return the first 4 bytes of the identifier, which children record as their parent fingerprint
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.identifier())
+    }
+
+    /// Serialize the key into the 78 byte BIP32 format
+    pub fn to_bytes(&self) -> [u8; SERIALIZED_LEN] {
+        serialize(
+            self.network.public_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &self.public_key.to_bytes(),
+        )
+    }
+}
+
+impl From<&ExtendedPrivateKey> for ExtendedPublicKey {
+    fn from(key: &ExtendedPrivateKey) -> Self {
+        Self {
+            network: key.network,
+            depth: key.depth,
+            parent_fingerprint: key.parent_fingerprint,
+            child_number: key.child_number,
+            chain_code: *key.chain_code(),
+            public_key: key.public_key(),
+        }
+    }
+}
+
+impl Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", base58::encode_check(&self.to_bytes()))
+    }
+}
+
+impl From<ExtendedPublicKey> for String {
+    fn from(key: ExtendedPublicKey) -> String {
+        key.to_string()
+    }
+}
+
+impl TryFrom<&[u8]> for ExtendedPublicKey {
+    type Error = Error;
+    /// Parse a key from the 78 byte BIP32 format
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let (version, depth, parent_fingerprint, child_number, chain_code, key) =
+            deserialize(bytes)?;
+
+        let network = if version == Network::Mainnet.public_version() {
+            Network::Mainnet
+        } else if version == Network::Testnet.public_version() {
+            Network::Testnet
+        } else {
+            return Err(Error::UnknownVersion(version));
+        };
+
+        if key[0] != 2 && key[0] != 3 {
+            return Err(Error::InvalidKey);
+        }
+        let public_key = PublicKey::try_from(&key[..]).map_err(|_| Error::InvalidKey)?;
+
+        Ok(Self {
+            network,
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            public_key,
+        })
+    }
+}
+
+impl TryFrom<&str> for ExtendedPublicKey {
+    type Error = Error;
+    /// Parse a key from its xpub or tpub Base58Check string
+    fn try_from(s: &str) -> Result<Self, Error> {
+        match base58::decode_check(s) {
+            Ok(bytes) => Self::try_from(&bytes[..]),
+            Err(e) => Err(Error::Conversion(ConversionError::Base58(e))),
+        }
+    }
+}
+
+/// Return HMAC-SHA512(key, data) split into its left and right halves
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for d in data {
+        mac.update(d);
+    }
+    let i = mac.finalize().into_bytes();
+
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&i[..32]);
+    ir.copy_from_slice(&i[32..]);
+
+    (il, ir)
+}
+
+/// Interpret 32 bytes as a scalar, failing if they are not less than the group order
+fn parse_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    if bytes[..] < N[..] {
+        Some(Scalar::from(*bytes))
+    } else {
+        None
+    }
+}
+
+fn fingerprint(identifier: &[u8; 20]) -> [u8; 4] {
+    let mut fp = [0u8; 4];
+    fp.copy_from_slice(&identifier[..4]);
+    fp
+}
+
+fn serialize(
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: &[u8; 32],
+    key: &[u8; 33],
+) -> [u8; SERIALIZED_LEN] {
+    let mut bytes = [0u8; SERIALIZED_LEN];
+
+    bytes[..4].copy_from_slice(&version);
+    bytes[4] = depth;
+    bytes[5..9].copy_from_slice(&parent_fingerprint);
+    bytes[9..13].copy_from_slice(&child_number.to_be_bytes());
+    bytes[13..45].copy_from_slice(chain_code);
+    bytes[45..].copy_from_slice(key);
+
+    bytes
+}
+
+#[allow(clippy::type_complexity)]
+fn deserialize(bytes: &[u8]) -> Result<([u8; 4], u8, [u8; 4], u32, [u8; 32], [u8; 33]), Error> {
+    if bytes.len() != SERIALIZED_LEN {
+        return Err(Error::Conversion(ConversionError::WrongNumberOfBytes(
+            bytes.len(),
+        )));
+    }
+
+    let mut version = [0u8; 4];
+    let mut parent_fingerprint = [0u8; 4];
+    let mut child_number = [0u8; 4];
+    let mut chain_code = [0u8; 32];
+    let mut key = [0u8; 33];
+
+    version.copy_from_slice(&bytes[..4]);
+    let depth = bytes[4];
+    parent_fingerprint.copy_from_slice(&bytes[5..9]);
+    child_number.copy_from_slice(&bytes[9..13]);
+    chain_code.copy_from_slice(&bytes[13..45]);
+    key.copy_from_slice(&bytes[45..]);
+
+    let child_number = u32::from_be_bytes(child_number);
+
+    // a master key has no parent, so it must have a zero fingerprint and child number
+    if depth == 0 && (parent_fingerprint != [0; 4] || child_number != 0) {
+        return Err(Error::InvalidKey);
+    }
+
+    Ok((
+        version,
+        depth,
+        parent_fingerprint,
+        child_number,
+        chain_code,
+        key,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_vector(seed: &str, chain: &[(&str, &str, &str)]) {
+        let seed = hex::decode(seed).unwrap();
+        let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+
+        for (path, xpub, xprv) in chain {
+            let path = DerivationPath::try_from(*path).unwrap();
+            let key = master.derive_path(&path).unwrap();
+
+            assert_eq!(key.to_string(), *xprv, "xprv at {}", path);
+            assert_eq!(
+                key.extended_public_key().to_string(),
+                *xpub,
+                "xpub at {}",
+                path
+            );
+
+            let parsed = ExtendedPrivateKey::try_from(*xprv).unwrap();
+            assert_eq!(parsed.to_string(), *xprv);
+            let parsed = ExtendedPublicKey::try_from(*xpub).unwrap();
+            assert_eq!(parsed, key.extended_public_key());
+        }
+    }
+
+    #[test]
+    fn bip32_vector_1() {
+        check_vector(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m",
+                    "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+                    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+                ),
+                (
+                    "m/0H",
+                    "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+                    "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+                ),
+                (
+                    "m/0H/1",
+                    "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+                    "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+                ),
+                (
+                    "m/0H/1/2H",
+                    "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+                    "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+                ),
+                (
+                    "m/0H/1/2H/2",
+                    "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+                    "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+                ),
+                (
+                    "m/0H/1/2H/2/1000000000",
+                    "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+                    "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn bip32_vector_2() {
+        check_vector(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            &[
+                (
+                    "m",
+                    "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB",
+                    "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+                ),
+                (
+                    "m/0",
+                    "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH",
+                    "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+                ),
+                (
+                    "m/0/2147483647H",
+                    "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a",
+                    "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+                ),
+                (
+                    "m/0/2147483647H/1",
+                    "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon",
+                    "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+                ),
+                (
+                    "m/0/2147483647H/1/2147483646H",
+                    "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL",
+                    "xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc",
+                ),
+                (
+                    "m/0/2147483647H/1/2147483646H/2",
+                    "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt",
+                    "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn bip32_vector_3() {
+        // retention of leading zeros in the private key
+        check_vector(
+            "4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be",
+            &[
+                (
+                    "m",
+                    "xpub661MyMwAqRbcEZVB4dScxMAdx6d4nFc9nvyvH3v4gJL378CSRZiYmhRoP7mBy6gSPSCYk6SzXPTf3ND1cZAceL7SfJ1Z3GC8vBgp2epUt13",
+                    "xprv9s21ZrQH143K25QhxbucbDDuQ4naNntJRi4KUfWT7xo4EKsHt2QJDu7KXp1A3u7Bi1j8ph3EGsZ9Xvz9dGuVrtHHs7pXeTzjuxBrCmmhgC6",
+                ),
+                (
+                    "m/0H",
+                    "xpub68NZiKmJWnxxS6aaHmn81bvJeTESw724CRDs6HbuccFQN9Ku14VQrADWgqbhhTHBaohPX4CjNLf9fq9MYo6oDaPPLPxSb7gwQN3ih19Zm4Y",
+                    "xprv9uPDJpEQgRQfDcW7BkF7eTya6RPxXeJCqCJGHuCJ4GiRVLzkTXBAJMu2qaMWPrS7AANYqdq6vcBcBUdJCVVFceUvJFjaPdGZ2y9WACViL4L",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn bip32_vector_4() {
+        // retention of leading zeros in the private key through hardened derivation
+        check_vector(
+            "3ddd5602285899a946114506157c7997e5444528f3003f6134712147db19b678",
+            &[
+                (
+                    "m",
+                    "xpub661MyMwAqRbcGczjuMoRm6dXaLDEhW1u34gKenbeYqAix21mdUKJyuyu5F1rzYGVxyL6tmgBUAEPrEz92mBXjByMRiJdba9wpnN37RLLAXa",
+                    "xprv9s21ZrQH143K48vGoLGRPxgo2JNkJ3J3fqkirQC2zVdk5Dgd5w14S7fRDyHH4dWNHUgkvsvNDCkvAwcSHNAQwhwgNMgZhLtQC63zxwhQmRv",
+                ),
+                (
+                    "m/0H",
+                    "xpub69AUMk3qDBi3uW1sXgjCmVjJ2G6WQoYSnNHyzkmdCHEhSZ4tBok37xfFEqHd2AddP56Tqp4o56AePAgCjYdvpW2PU2jbUPFKsav5ut6Ch1m",
+                    "xprv9vB7xEWwNp9kh1wQRfCCQMnZUEG21LpbR9NPCNN1dwhiZkjjeGRnaALmPXCX7SgjFTiCTT6bXes17boXtjq3xLpcDjzEuGLQBM5ohqkao9G",
+                ),
+                (
+                    "m/0H/1H",
+                    "xpub6BJA1jSqiukeaesWfxe6sNK9CCGaujFFSJLomWHprUL9DePQ4JDkM5d88n49sMGJxrhpjazuXYWdMf17C9T5XnxkopaeS7jGk1GyyVziaMt",
+                    "xprv9xJocDuwtYCMNAo3Zw76WENQeAS6WGXQ55RCy7tDJ8oALr4FWkuVoHJeHVAcAqiZLE7Je3vZJHxspZdFHfnBEjHqU5hG1Jaj32dVoS6XLT1",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn bip32_vector_5() {
+        // invalid extended keys, none of which may parse as either a private or a public key
+        for (key, reason) in [
+            ("xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6LBpB85b3D2yc8sfvZU521AAwdZafEz7mnzBBsz4wKY5fTtTQBm", "pubkey version / prvkey mismatch"),
+            ("xprv9s21ZrQH143K24Mfq5zL5MhWK9hUhhGbd45hLXo2Pq2oqzMMo63oStZzFGTQQD3dC4H2D5GBj7vWvSQaaBv5cxi9gafk7NF3pnBju6dwKvH", "prvkey version / pubkey mismatch"),
+            ("xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6Txnt3siSujt9RCVYsx4qHZGc62TG4McvMGcAUjeuwZdduYEvFn", "invalid pubkey prefix 04"),
+            ("xprv9s21ZrQH143K24Mfq5zL5MhWK9hUhhGbd45hLXo2Pq2oqzMMo63oStZzFGpWnsj83BHtEy5Zt8CcDr1UiRXuWCmTQLxEK9vbz5gPstX92JQ", "invalid prvkey prefix 04"),
+            ("xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6N8ZMMXctdiCjxTNq964yKkwrkBJJwpzZS4HS2fxvyYUA4q2Xe4", "invalid pubkey prefix 01"),
+            ("xprv9s21ZrQH143K24Mfq5zL5MhWK9hUhhGbd45hLXo2Pq2oqzMMo63oStZzFAzHGBP2UuGCqWLTAPLcMtD9y5gkZ6Eq3Rjuahrv17fEQ3Qen6J", "invalid prvkey prefix 01"),
+            ("xprv9s2SPatNQ9Vc6GTbVMFPFo7jsaZySyzk7L8n2uqKXJen3KUmvQNTuLh3fhZMBoG3G4ZW1N2kZuHEPY53qmbZzCHshoQnNf4GvELZfqTUrcv", "zero depth with non-zero parent fingerprint"),
+            ("xpub661no6RGEX3uJkY4bNnPcw4URcQTrSibUZ4NqJEw5eBkv7ovTwgiT91XX27VbEXGENhYRCf7hyEbWrR3FewATdCEebj6znwMfQkhRYHRLpJ", "zero depth with non-zero parent fingerprint"),
+            ("xprv9s21ZrQH4r4TsiLvyLXqM9P7k1K3EYhA1kkD6xuquB5i39AU8KF42acDyL3qsDbU9NmZn6MsGSUYZEsuoePmjzsB3eFKSUEh3Gu1N3cqVUN", "zero depth with non-zero index"),
+            ("xpub661MyMwAuDcm6CRQ5N4qiHKrJ39Xe1R1NyfouMKTTWcguwVcfrZJaNvhpebzGerh7gucBvzEQWRugZDuDXjNDRmXzSZe4c7mnTK97pTvGS8", "zero depth with non-zero index"),
+            ("DMwo58pR1QLEFihHiXPVykYB6fJmsTeHvyTp7hRThAtCX8CvYzgPcn8XnmdfHGMQzT7ayAmfo4z3gY5KfbrZWZ6St24UVf2Qgo6oujFktLHdHY4", "unknown extended key version"),
+            ("DMwo58pR1QLEFihHiXPVykYB6fJmsTeHvyTp7hRThAtCX8CvYzgPcn8XnmdfHPmHJiEDXkTiJTVV9rHEBUem2mwVbbNfvT2MTcAqj3nesx8uBf9", "unknown extended key version"),
+            ("xprv9s21ZrQH143K24Mfq5zL5MhWK9hUhhGbd45hLXo2Pq2oqzMMo63oStZzF93Y5wvzdUayhgkkFoicQZcP3y52uPPxFnfoLZB21Teqt1VvEHx", "private key 0 not in 1..n-1"),
+            ("xprv9s21ZrQH143K24Mfq5zL5MhWK9hUhhGbd45hLXo2Pq2oqzMMo63oStZzFAzHGBP2UuGCqWLTAPLcMtD5SDKr24z3aiUvKr9bJpdrcLg1y3G", "private key n not in 1..n-1"),
+            ("xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6Q5JXayek4PRsn35jii4veMimro1xefsM58PgBMrvdYre8QyULY", "invalid pubkey 020000000000000000000000000000000000000000000000000000000000000007"),
+            ("xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHL", "invalid checksum"),
+        ] {
+            let private = ExtendedPrivateKey::try_from(key);
+            let public = ExtendedPublicKey::try_from(key);
+            assert!(private.is_err(), "{}", reason);
+            assert!(public.is_err(), "{}", reason);
+
+            // only the last vector should fail before its contents are checked
+            let checksum = matches!(
+                private,
+                Err(Error::Conversion(ConversionError::Base58(
+                    crate::errors::Base58Error::Checksum
+                )))
+            );
+            assert_eq!(checksum, reason == "invalid checksum", "{}", reason);
+        }
+    }
+
+    #[test]
+    fn public_derivation() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivateKey::new_master(&seed, Network::Testnet).unwrap();
+        let account = master
+            .derive_path(&DerivationPath::try_from("m/84'/1'/0'").unwrap())
+            .unwrap();
+        let xpub = account.extended_public_key();
+
+        for i in 0..4 {
+            let path = DerivationPath::from(vec![0, i]);
+            let from_private = account.derive_path(&path).unwrap().extended_public_key();
+            let from_public = xpub.derive_path(&path).unwrap();
+
+            assert_eq!(from_private, from_public);
+            assert_eq!(
+                from_public.parent_fingerprint,
+                xpub.derive_child(0).unwrap().fingerprint()
+            );
+        }
+
+        assert_eq!(xpub.derive_child(HARDENED), Err(Error::HardenedFromPublic));
+        assert!(xpub.to_string().starts_with("tpub"));
+        assert!(account.to_string().starts_with("tprv"));
+        assert_eq!(
+            ExtendedPrivateKey::try_from(account.to_string().as_str())
+                .unwrap()
+                .network,
+            Network::Testnet
+        );
+        assert_eq!(account.depth, 3);
+        assert_eq!(account.child_number, HARDENED);
+
+        // signet and regtest keys are serialized exactly as testnet keys are
+        for network in [Network::Signet, Network::Regtest] {
+            let key = ExtendedPrivateKey::new_master(&seed, network).unwrap();
+            assert_eq!(
+                key.to_string(),
+                ExtendedPrivateKey::new_master(&seed, Network::Testnet)
+                    .unwrap()
+                    .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn derivation_path() {
+        let path = DerivationPath::try_from("m/84'/0'/0'/0/1").unwrap();
+        assert_eq!(path.path, vec![84 + HARDENED, HARDENED, HARDENED, 0, 1]);
+        assert_eq!(path.to_string(), "m/84'/0'/0'/0/1");
+        assert!(path.is_hardened());
+
+        assert_eq!(
+            DerivationPath::try_from("m/0h/1H/2").unwrap().path,
+            vec![HARDENED, 1 + HARDENED, 2]
+        );
+        assert!(DerivationPath::try_from("m").unwrap().path.is_empty());
+
+        for bad in [
+            "",
+            "84'/0'",
+            "m/",
+            "m//1",
+            "m/x",
+            "m/-1",
+            "m/2147483648",
+            "m/1''",
+            "n/1",
+        ] {
+            assert!(DerivationPath::try_from(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+
+        assert_eq!(
+            ExtendedPrivateKey::new_master(&seed[..15], Network::Mainnet).unwrap_err(),
+            Error::InvalidSeedLength(15)
+        );
+
+        let xprv = master.to_string();
+        let mut corrupt = xprv.clone().into_bytes();
+        corrupt[20] = if corrupt[20] == b'a' { b'b' } else { b'a' };
+        assert!(matches!(
+            ExtendedPrivateKey::try_from(std::str::from_utf8(&corrupt).unwrap()),
+            Err(Error::Conversion(ConversionError::Base58(
+                crate::errors::Base58Error::Checksum
+            )))
+        ));
+
+        // private and public keys can't be parsed as each other
+        let xpub = master.extended_public_key().to_string();
+        assert!(matches!(
+            ExtendedPrivateKey::try_from(xpub.as_str()),
+            Err(Error::UnknownVersion(_))
+        ));
+        assert!(matches!(
+            ExtendedPublicKey::try_from(xprv.as_str()),
+            Err(Error::UnknownVersion(_))
+        ));
+
+        // a master key with a nonzero parent fingerprint
+        let mut bytes = master.to_bytes();
+        bytes[5] = 1;
+        assert_eq!(
+            ExtendedPrivateKey::try_from(&bytes[..]).unwrap_err(),
+            Error::InvalidKey
+        );
+
+        // a private key not less than the group order
+        let mut bytes = master.to_bytes();
+        bytes[46..].copy_from_slice(&N);
+        assert_eq!(
+            ExtendedPrivateKey::try_from(&bytes[..]).unwrap_err(),
+            Error::InvalidKey
+        );
+
+        assert!(!format!("{:?}", master).contains(&hex::encode(master.private_key().to_bytes())));
+
+        let mut key = master.clone();
+        key.zeroize();
+        assert_eq!(key.chain_code(), &[0; 32]);
+        assert!(key.private_key().is_zero());
+    }
+}
@@ -0,0 +1,316 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use crate::{
+    asn1::{base64url_decode, base64url_encode},
+    field,
+    keys::{Error as KeyError, KeyPair, PublicKey},
+    point::{self, Point},
+};
+
+/// The kty of elliptic curve keys
+pub const KEY_TYPE: &str = "EC";
+/// The crv of secp256k1 keys, as RFC 8812 registers it
+pub const CURVE: &str = "secp256k1";
+
+#[derive(Debug, Clone)]
+/// Errors in JSON Web Key conversion
+pub enum Error {
+    /// The kty was not EC
+    UnsupportedKeyType(String),
+    /// The crv was not secp256k1
+    UnsupportedCurve(String),
+    /// The named member was not unpadded base64url of 32 bytes
+    InvalidEncoding(&'static str),
+    /// The key had no d member
+    MissingSecretKey,
+    /// A coordinate was not less than the field size
+    Field(field::Error),
+    /// The coordinates were not a point on the curve
+    Point(point::Error),
+    /// The key was invalid
+    Key(KeyError),
+    /// The d member didn't match the x and y members
+    PublicKeyMismatch,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+/**
+A JSON Web Key for a secp256k1 key as RFC 7517 and RFC 8812 specify, with the coordinates and optional secret key as unpadded base64url of 32 big endian bytes.  It is read and written as JSON through serde, and members other than these, such as kid or use, are ignored when deserializing.  The secret key is zeroed when the Jwk is dropped, and redacted from its Debug output.
+*/
+pub struct Jwk {
+    /// The key type, which must be EC
+    pub kty: String,
+    /// The curve, which must be secp256k1
+    pub crv: String,
+    /// The x coordinate of the public key
+    pub x: String,
+    /// The y coordinate of the public key
+    pub y: String,
+    /// The secret key, if this is a private JWK
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+}
+
+impl Jwk {
+    /// Return the public JWK of the public key
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        let point = Point::from(public_key);
+
+        Self {
+            kty: KEY_TYPE.to_string(),
+            crv: CURVE.to_string(),
+            x: base64url_encode(&point.x().to_bytes()),
+            y: base64url_encode(&point.y().to_bytes()),
+            d: None,
+        }
+    }
+
+    /// Return the private JWK of the keypair, which includes the d member
+    pub fn from_key_pair(key_pair: &KeyPair) -> Self {
+        let mut jwk = Self::from_public_key(&key_pair.public_key());
+
+        jwk.d = Some(base64url_encode(key_pair.secret_bytes().as_ref()));
+
+        jwk
+    }
+
+    /// Return the public key of this JWK, checking its kty, crv and that its coordinates are on the curve
+    pub fn public_key(&self) -> Result<PublicKey, Error> {
+        if self.kty != KEY_TYPE {
+            return Err(Error::UnsupportedKeyType(self.kty.clone()));
+        }
+        if self.crv != CURVE {
+            return Err(Error::UnsupportedCurve(self.crv.clone()));
+        }
+
+        let x = coordinate("x", &self.x)?;
+        let y = coordinate("y", &self.y)?;
+        let point = Point::from_affine(&x, &y).map_err(Error::Point)?;
+
+        PublicKey::from_point(&point).map_err(Error::Key)
+    }
+
+    /// Return the keypair of this JWK, checking that its d member matches its public key
+    pub fn key_pair(&self) -> Result<KeyPair, Error> {
+        let public_key = self.public_key()?;
+        let d = self.d.as_ref().ok_or(Error::MissingSecretKey)?;
+
+        let mut bytes = base64url_decode(d).ok_or(Error::InvalidEncoding("d"))?;
+        let key_pair = match <[u8; 32]>::try_from(&bytes[..]) {
+            Ok(mut secret) => {
+                let key_pair = KeyPair::from_secret_bytes(&secret).map_err(Error::Key);
+                secret.zeroize();
+                key_pair
+            }
+            Err(_) => Err(Error::InvalidEncoding("d")),
+        };
+        bytes.zeroize();
+
+        let key_pair = key_pair?;
+        if key_pair.public_key() != public_key {
+            return Err(Error::PublicKeyMismatch);
+        }
+
+        Ok(key_pair)
+    }
+
+    /// Return the JWK without its secret key
+    pub fn to_public(&self) -> Self {
+        let mut jwk = self.clone();
+        jwk.d.zeroize();
+        jwk
+    }
+
+    /// Return the RFC 7638 thumbprint, which is the SHA-256 of the canonical JSON of the crv, kty, x and y members; the secret key does not affect it, but the public key must be valid
+    pub fn thumbprint(&self) -> Result<[u8; 32], Error> {
+        // re-encode the checked public key, so the members are base64url or the constants and none of them need escaping
+        let jwk = Self::from_public_key(&self.public_key()?);
+
+        // the required members in lexicographic order with no whitespace
+        let json = format!(
+            r#"{{"crv":"{}","kty":"{}","x":"{}","y":"{}"}}"#,
+            jwk.crv, jwk.kty, jwk.x, jwk.y
+        );
+
+        Ok(Sha256::digest(json.as_bytes()).into())
+    }
+
+    /// Return the RFC 7638 thumbprint as unpadded base64url, as is used for a kid
+    pub fn thumbprint_base64url(&self) -> Result<String, Error> {
+        Ok(base64url_encode(&self.thumbprint()?))
+    }
+}
+
+impl Debug for Jwk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("d", &self.d.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        self.d.zeroize();
+    }
+}
+
+impl From<&PublicKey> for Jwk {
+    fn from(public_key: &PublicKey) -> Self {
+        Self::from_public_key(public_key)
+    }
+}
+
+impl From<&KeyPair> for Jwk {
+    fn from(key_pair: &KeyPair) -> Self {
+        Self::from_key_pair(key_pair)
+    }
+}
+
+fn coordinate(name: &'static str, s: &str) -> Result<field::Element, Error> {
+    let bytes = base64url_decode(s).ok_or(Error::InvalidEncoding(name))?;
+    if bytes.len() != 32 {
+        return Err(Error::InvalidEncoding(name));
+    }
+
+    field::Element::try_from(&bytes[..]).map_err(Error::Field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::Scalar;
+
+    // the key of the OpenSSL fixtures in pkcs, with the thumbprint computed independently
+    const SECRET: &str = "d17ab2261648718d1995ea624d7ee31072133b6a18573dde7ca6d7535a512f5c";
+    const X: &str = "5ewbRENiRB8kSl2MoZzdqcVEDCZO4WmKRAtoZqAMTdU";
+    const Y: &str = "UeKGf5ZTEUYcFwmUqRnmGea1H9_xH598jyVVBdSm_L8";
+    const D: &str = "0XqyJhZIcY0ZlepiTX7jEHITO2oYVz3efKbXU1pRL1w";
+    const THUMBPRINT: &str = "6kv8YEKmY_QA96PLrhJ3PQw6JXmhhSEKJvb7uZsKJtk";
+
+    fn key_pair() -> KeyPair {
+        let secret: [u8; 32] = hex::decode(SECRET).unwrap().try_into().unwrap();
+        KeyPair::from_secret_bytes(&secret).unwrap()
+    }
+
+    #[test]
+    fn export() {
+        let key_pair = key_pair();
+        let public_key = key_pair.public_key();
+
+        let jwk = public_key.to_jwk();
+        assert_eq!(
+            serde_json::to_string(&jwk).unwrap(),
+            format!(
+                r#"{{"kty":"EC","crv":"secp256k1","x":"{}","y":"{}"}}"#,
+                X, Y
+            )
+        );
+        assert_eq!(jwk.thumbprint_base64url().unwrap(), THUMBPRINT);
+
+        let private = key_pair.to_jwk();
+        assert_eq!(private.d.as_deref(), Some(D));
+        assert_eq!(private.to_public(), jwk);
+        assert_eq!(private.thumbprint().unwrap(), jwk.thumbprint().unwrap());
+        assert!(!format!("{:?}", private).contains(D));
+    }
+
+    #[test]
+    fn import() {
+        let key_pair = key_pair();
+
+        // member order, whitespace and extra members don't matter
+        let s = format!(
+            r#"{{ "y": "{}", "x": "{}", "d": "{}", "crv": "secp256k1", "kty": "EC", "kid": "{}" }}"#,
+            Y, X, D, THUMBPRINT
+        );
+        let jwk: Jwk = serde_json::from_str(&s).unwrap();
+        assert_eq!(KeyPair::from_jwk(&jwk).unwrap(), key_pair);
+        assert_eq!(PublicKey::from_jwk(&jwk).unwrap(), key_pair.public_key());
+        assert_eq!(jwk.thumbprint_base64url().unwrap(), THUMBPRINT);
+
+        assert!(matches!(
+            KeyPair::from_jwk(&jwk.to_public()),
+            Err(Error::MissingSecretKey)
+        ));
+
+        for i in 1..8 {
+            let key_pair = KeyPair::new(&Scalar::from(i)).unwrap();
+            let json = serde_json::to_string(&key_pair.to_jwk()).unwrap();
+            let jwk: Jwk = serde_json::from_str(&json).unwrap();
+            assert_eq!(KeyPair::from_jwk(&jwk).unwrap(), key_pair);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let good = key_pair().to_jwk();
+
+        let mut jwk = good.clone();
+        jwk.kty = "OKP".to_string();
+        assert!(matches!(
+            jwk.public_key(),
+            Err(Error::UnsupportedKeyType(_))
+        ));
+
+        // members which would need escaping are rejected rather than spliced into the thumbprint JSON
+        let mut jwk = good.clone();
+        jwk.kty = r#"EC","z":""#.to_string();
+        assert!(matches!(
+            jwk.thumbprint(),
+            Err(Error::UnsupportedKeyType(_))
+        ));
+        let mut jwk = good.clone();
+        jwk.x = format!("{}\"", X);
+        assert!(matches!(
+            jwk.thumbprint_base64url(),
+            Err(Error::InvalidEncoding("x"))
+        ));
+
+        let mut jwk = good.clone();
+        jwk.crv = "P-256".to_string();
+        assert!(matches!(jwk.public_key(), Err(Error::UnsupportedCurve(_))));
+
+        // padded, standard alphabet and short coordinates
+        for y in [format!("{}=", Y), Y.replace('_', "/"), Y[1..].to_string()] {
+            let mut jwk = good.clone();
+            jwk.y = y;
+            assert!(matches!(jwk.public_key(), Err(Error::InvalidEncoding("y"))));
+        }
+
+        // y of the negated point
+        let mut jwk = good.clone();
+        jwk.y = base64url_encode(&(-Point::from(&key_pair().public_key())).y().to_bytes());
+        assert!(jwk.public_key().is_ok());
+        assert!(matches!(jwk.key_pair(), Err(Error::PublicKeyMismatch)));
+
+        let mut jwk = good.clone();
+        jwk.y = X.to_string();
+        assert!(matches!(jwk.public_key(), Err(Error::Point(_))));
+
+        let mut jwk = good.clone();
+        jwk.x = base64url_encode(&[0xFF; 32]);
+        assert!(matches!(jwk.public_key(), Err(Error::Field(_))));
+
+        let mut jwk = good;
+        jwk.d = Some(base64url_encode(&[0; 32]));
+        assert!(matches!(jwk.key_pair(), Err(Error::Key(_))));
+
+        assert!(serde_json::from_str::<Jwk>(r#"{"kty":"EC","crv":"secp256k1","x":"AA"}"#).is_err());
+    }
+}
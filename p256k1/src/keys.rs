@@ -1,23 +1,38 @@
 use bs58;
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
-use serde::{
-    de::{self, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
-use std::{array::TryFromSliceError, hash::Hash};
+use ripemd::Ripemd160;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::{array::TryFromSliceError, hash::Hash, str::FromStr};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 use crate::_rename::{
-    secp256k1_ec_pubkey_create, secp256k1_ec_pubkey_parse, secp256k1_ec_pubkey_serialize,
-    secp256k1_keypair_create, secp256k1_keypair_pub, secp256k1_keypair_sec,
-    secp256k1_keypair_xonly_pub, secp256k1_xonly_pubkey_from_pubkey, secp256k1_xonly_pubkey_parse,
-    secp256k1_xonly_pubkey_serialize,
+    secp256k1_ec_pubkey_combine, secp256k1_ec_pubkey_create, secp256k1_ec_pubkey_negate,
+    secp256k1_ec_pubkey_parse, secp256k1_ec_pubkey_serialize, secp256k1_ec_pubkey_tweak_add,
+    secp256k1_ec_pubkey_tweak_mul, secp256k1_ec_seckey_negate, secp256k1_ec_seckey_tweak_add,
+    secp256k1_ec_seckey_tweak_mul, secp256k1_ellswift_xdh,
+    secp256k1_ellswift_xdh_hash_function_bip324, secp256k1_keypair_create, secp256k1_keypair_pub,
+    secp256k1_keypair_sec, secp256k1_keypair_xonly_pub, secp256k1_keypair_xonly_tweak_add,
+    secp256k1_tagged_sha256, secp256k1_xonly_pubkey_from_pubkey, secp256k1_xonly_pubkey_parse,
+    secp256k1_xonly_pubkey_serialize, secp256k1_xonly_pubkey_tweak_add,
+    secp256k1_xonly_pubkey_tweak_add_check,
 };
+use crate::asn1;
+use crate::base58;
 use crate::bindings::{
     secp256k1_keypair, secp256k1_pubkey, secp256k1_xonly_pubkey, SECP256K1_EC_COMPRESSED,
+    SECP256K1_EC_UNCOMPRESSED,
 };
 use crate::context::Context;
 use crate::errors::{Base58Error, ConversionError};
+use crate::eth;
+use crate::group::secp256k1_pubkey_save;
+use crate::jwk::{self, Jwk};
+use crate::network::Network;
+use crate::pkcs;
+use crate::point::{Parity, Point};
 use crate::scalar::Scalar;
+use crate::traits::{self, FixedBytes, StringFormats};
 
 #[derive(Debug, Clone)]
 /// Errors in ECDSA signature operations
@@ -32,6 +47,12 @@ pub enum Error {
     TryFrom(String),
     /// Error converting a scalar
     Conversion(ConversionError),
+    /// Error occurred because a tweak would produce an invalid key
+    InvalidTweak,
+    /// Error occurred because there were no keys to combine or they summed to the identity
+    InvalidCombination,
+    /// Error occurred because a WIF string had an unknown version byte or compression flag
+    InvalidWif,
 }
 
 impl Display for Error {
@@ -42,6 +63,12 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<ConversionError> for Error {
+    fn from(e: ConversionError) -> Self {
+        Error::Conversion(e)
+    }
+}
+
 impl From<TryFromSliceError> for Error {
     fn from(e: TryFromSliceError) -> Self {
         Error::TryFrom(e.to_string())
@@ -73,6 +100,20 @@ impl PublicKey {
         Ok(pub_key)
     }
 
+    /// Construct a public key directly from a point without encoding and parsing it, failing for the identity or a point off the curve
+    pub fn from_point(point: &Point) -> Result<Self, Error> {
+        if point.is_identity() || !point.is_on_curve() {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut pub_key = Self {
+            key: secp256k1_pubkey { data: [0; 64] },
+        };
+        secp256k1_pubkey_save(&mut pub_key.key.data, &point.affine());
+
+        Ok(pub_key)
+    }
+
     /// Serialize the key to a compressed byte array
     pub fn to_bytes(&self) -> [u8; 33] {
         let ctx = Context::default();
@@ -91,6 +132,129 @@ impl PublicKey {
 
         bytes
     }
+
+    /// Serialize the key to an uncompressed byte array
+    pub fn to_uncompressed_bytes(&self) -> [u8; 65] {
+        let ctx = Context::default();
+        let mut bytes = [0u8; 65];
+        let mut len = bytes.len();
+
+        unsafe {
+            secp256k1_ec_pubkey_serialize(
+                ctx.context,
+                bytes.as_mut_ptr(),
+                &mut len,
+                &self.key,
+                SECP256K1_EC_UNCOMPRESSED,
+            );
+        }
+
+        bytes
+    }
+
+    /// Serialize the key as its 64 byte x and y coordinates, which is the uncompressed encoding without its 0x04 tag
+    pub fn to_xy_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&self.to_uncompressed_bytes()[1..]);
+        bytes
+    }
+
+    /// Return the Ethereum address of the key, which is the last 20 bytes of the keccak256 of its x and y coordinates
+    pub fn eth_address(&self) -> eth::Address {
+        eth::Address::new(self)
+    }
+
+    /// Return RIPEMD160(SHA256(compressed key)), which identifies the key in BIP32 fingerprints and Bitcoin addresses
+    pub fn hash160(&self) -> [u8; 20] {
+        let sha = Sha256::digest(self.to_bytes());
+        Ripemd160::digest(sha).into()
+    }
+
+    /// Return the key for the point self + tweak*G, failing if that is the identity
+    pub fn tweak_add(&self, tweak: &Scalar) -> Result<Self, Error> {
+        let mut pub_key = *self;
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_ec_pubkey_tweak_add(ctx.context, &mut pub_key.key, tweak.to_bytes().as_ptr())
+        } == 0
+        {
+            return Err(Error::InvalidTweak);
+        }
+        Ok(pub_key)
+    }
+
+    /// Return the key for the point tweak*self, failing if the tweak is zero
+    pub fn tweak_mul(&self, tweak: &Scalar) -> Result<Self, Error> {
+        let mut pub_key = *self;
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_ec_pubkey_tweak_mul(ctx.context, &mut pub_key.key, tweak.to_bytes().as_ptr())
+        } == 0
+        {
+            return Err(Error::InvalidTweak);
+        }
+        Ok(pub_key)
+    }
+
+    /// Return the key for the point -self
+    pub fn negate(&self) -> Self {
+        let mut pub_key = *self;
+        let ctx = Context::default();
+        unsafe {
+            secp256k1_ec_pubkey_negate(ctx.context, &mut pub_key.key);
+        }
+        pub_key
+    }
+
+    /// Return the key for the sum of the passed keys, failing if there are none or they sum to the identity
+    pub fn combine(keys: &[PublicKey]) -> Result<Self, Error> {
+        if keys.is_empty() {
+            return Err(Error::InvalidCombination);
+        }
+
+        let mut pub_key = Self {
+            key: secp256k1_pubkey { data: [0; 64] },
+        };
+        let ptrs: Vec<*const secp256k1_pubkey> = keys.iter().map(|k| &k.key as *const _).collect();
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_ec_pubkey_combine(ctx.context, &mut pub_key.key, ptrs.as_ptr(), ptrs.len())
+        } == 0
+        {
+            return Err(Error::InvalidCombination);
+        }
+        Ok(pub_key)
+    }
+
+    /// Encode the public key as an uncompressed SubjectPublicKeyInfo in DER
+    pub fn to_spki_der(&self) -> Vec<u8> {
+        pkcs::spki_encode(self)
+    }
+
+    /// Decode a public key from a SubjectPublicKeyInfo in DER, with the point compressed or uncompressed
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, pkcs::Error> {
+        pkcs::spki_decode(der)
+    }
+
+    /// Encode the public key as a SubjectPublicKeyInfo `PUBLIC KEY` PEM block
+    pub fn to_spki_pem(&self) -> String {
+        asn1::pem_encode(pkcs::SPKI_LABEL, &self.to_spki_der())
+    }
+
+    /// Decode a public key from the first SubjectPublicKeyInfo `PUBLIC KEY` PEM block
+    pub fn from_spki_pem(pem: &str) -> Result<Self, pkcs::Error> {
+        Self::from_spki_der(&asn1::pem_decode(pkcs::SPKI_LABEL, pem)?)
+    }
+
+    /// Return the public JSON Web Key of this key
+    pub fn to_jwk(&self) -> Jwk {
+        Jwk::from_public_key(self)
+    }
+
+    /// Return the public key of a JSON Web Key, ignoring any secret key it holds
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.public_key()
+    }
 }
 
 impl Debug for PublicKey {
@@ -133,45 +297,34 @@ impl Hash for PublicKey {
     }
 }
 
-impl Serialize for PublicKey {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(&self.to_bytes())
-    }
-}
+/// Public keys are encoded in the 33 byte compressed format
+impl StringFormats for PublicKey {
+    type Bytes = [u8; 33];
+    type Error = Error;
 
-struct PublicKeyVisitor;
+    const DESCRIPTION: &'static str = "a ECDSA public key";
 
-impl<'de> Visitor<'de> for PublicKeyVisitor {
-    type Value = PublicKey;
+    fn encode_bytes(&self) -> [u8; 33] {
+        self.to_bytes()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an array of bytes which represents a ECDSA public key")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        PublicKey::try_from(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match PublicKey::try_from(value) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<33> for PublicKey {
+    fn to_fixed_bytes(&self) -> [u8; 33] {
+        self.to_bytes()
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -180,7 +333,7 @@ impl<'de> Deserialize<'de> for PublicKey {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(PublicKeyVisitor)
+        traits::deserialize(deserializer)
     }
 }
 
@@ -192,6 +345,22 @@ impl TryFrom<&Scalar> for PublicKey {
     }
 }
 
+impl TryFrom<&Point> for PublicKey {
+    type Error = Error;
+
+    fn try_from(point: &Point) -> Result<Self, Self::Error> {
+        Self::from_point(point)
+    }
+}
+
+impl TryFrom<Point> for PublicKey {
+    type Error = Error;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Self::from_point(&point)
+    }
+}
+
 impl TryFrom<&str> for PublicKey {
     type Error = Error;
     /// Create a pubkey from the passed byte slice
@@ -205,6 +374,15 @@ impl TryFrom<&str> for PublicKey {
     }
 }
 
+impl FromStr for PublicKey {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        PublicKey::try_from(s)
+    }
+}
+
 impl TryFrom<&[u8]> for PublicKey {
     type Error = Error;
     /// Create a pubkey from the passed byte slice
@@ -245,6 +423,63 @@ impl XOnlyPublicKey {
         Ok(Self::from(&public_key))
     }
 
+    /// Construct an x-only public key directly from a point, negating it if its y is odd and recording that in the parity, failing for the identity or a point off the curve
+    pub fn from_point(point: &Point) -> Result<Self, Error> {
+        if point.is_identity() || !point.is_on_curve() {
+            return Err(Error::InvalidXOnlyPublicKey);
+        }
+
+        let (even, parity) = point.to_even_y();
+        let mut pub_key = Self {
+            key: secp256k1_xonly_pubkey { data: [0; 64] },
+            parity: parity.into(),
+        };
+        secp256k1_pubkey_save(&mut pub_key.key.data, &even.affine());
+
+        Ok(pub_key)
+    }
+
+    /// Return the parity of the y coordinate of the full point this key was derived from, which is even for parsed keys
+    pub fn parity(&self) -> Parity {
+        Parity::from(self.parity == 1)
+    }
+
+    /// Return the x-only key for the point self + tweak*G along with the parity of its y coordinate, as used for taproot output keys; fails if the tweak is not less than the group order or the result is the identity
+    pub fn tweak_add(&self, tweak: &[u8; 32]) -> Result<(Self, Parity), Error> {
+        let mut full = PublicKey {
+            key: secp256k1_pubkey { data: [0; 64] },
+        };
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_xonly_pubkey_tweak_add(ctx.context, &mut full.key, &self.key, tweak.as_ptr())
+        } == 0
+        {
+            return Err(Error::InvalidTweak);
+        }
+
+        let tweaked = XOnlyPublicKey::from(&full);
+        Ok((tweaked, tweaked.parity()))
+    }
+
+    /// Return true if tweaked with the passed parity is the result of tweaking self with tweak, as when verifying a taproot commitment
+    pub fn tweak_add_check(
+        &self,
+        tweaked: &XOnlyPublicKey,
+        parity: Parity,
+        tweak: &[u8; 32],
+    ) -> bool {
+        let ctx = Context::default();
+        unsafe {
+            secp256k1_xonly_pubkey_tweak_add_check(
+                ctx.context,
+                tweaked.to_bytes().as_ptr(),
+                parity.into(),
+                &self.key,
+                tweak.as_ptr(),
+            ) == 1
+        }
+    }
+
     /// Serialize the key to a compressed byte array
     pub fn to_bytes(&self) -> [u8; 32] {
         let ctx = Context::default();
@@ -298,45 +533,34 @@ impl Hash for XOnlyPublicKey {
     }
 }
 
-impl Serialize for XOnlyPublicKey {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(&self.to_bytes())
-    }
-}
+/// X-only public keys are encoded as the 32 byte x coordinate
+impl StringFormats for XOnlyPublicKey {
+    type Bytes = [u8; 32];
+    type Error = Error;
 
-struct XOnlyPublicKeyVisitor;
+    const DESCRIPTION: &'static str = "an x-only public key";
 
-impl<'de> Visitor<'de> for XOnlyPublicKeyVisitor {
-    type Value = XOnlyPublicKey;
+    fn encode_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an array of bytes which represents a ECDSA public key")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        XOnlyPublicKey::try_from(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match XOnlyPublicKey::try_from(value) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<32> for XOnlyPublicKey {
+    fn to_fixed_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for XOnlyPublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -345,7 +569,7 @@ impl<'de> Deserialize<'de> for XOnlyPublicKey {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(XOnlyPublicKeyVisitor)
+        traits::deserialize(deserializer)
     }
 }
 
@@ -357,6 +581,22 @@ impl TryFrom<&Scalar> for XOnlyPublicKey {
     }
 }
 
+impl TryFrom<&Point> for XOnlyPublicKey {
+    type Error = Error;
+
+    fn try_from(point: &Point) -> Result<Self, Self::Error> {
+        Self::from_point(point)
+    }
+}
+
+impl TryFrom<Point> for XOnlyPublicKey {
+    type Error = Error;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Self::from_point(&point)
+    }
+}
+
 impl TryFrom<&str> for XOnlyPublicKey {
     type Error = Error;
     /// Create a pubkey from the passed byte slice
@@ -370,6 +610,15 @@ impl TryFrom<&str> for XOnlyPublicKey {
     }
 }
 
+impl FromStr for XOnlyPublicKey {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        XOnlyPublicKey::try_from(s)
+    }
+}
+
 impl TryFrom<&[u8]> for XOnlyPublicKey {
     type Error = Error;
     /// Create a pubkey from the passed byte slice
@@ -412,9 +661,9 @@ impl From<&PublicKey> for XOnlyPublicKey {
 }
 
 /**
-KeyPair is a wrapper around libsecp256k1's secp256k1_keypair struct.
+KeyPair is a wrapper around libsecp256k1's secp256k1_keypair struct.  It holds a secret key, so it is not Copy, its Debug output is redacted, and its memory is zeroed when dropped.  Use keypair_secret to opt in to serializing it.
 */
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct KeyPair {
     /// The wrapped secp256k1_keypair
     pub(crate) key: secp256k1_keypair,
@@ -423,17 +672,188 @@ pub struct KeyPair {
 impl KeyPair {
     /// Construct a keypair from a given secret key
     pub fn new(sec_key: &Scalar) -> Result<Self, Error> {
-        let mut pub_key = Self {
+        let bytes = Zeroizing::new(sec_key.to_bytes());
+        Self::from_secret_bytes(&bytes)
+    }
+
+    /// Construct a keypair from big endian secret key bytes, failing if they are zero or not less than the group order
+    pub fn from_secret_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let mut key_pair = Self {
             key: secp256k1_keypair { data: [0; 96] },
         };
         let ctx = Context::default();
+        if unsafe { secp256k1_keypair_create(ctx.context, &mut key_pair.key, bytes.as_ptr()) } == 0
+        {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(key_pair)
+    }
+
+    /// Export the secret key as big endian bytes, which are zeroed when dropped
+    pub fn secret_bytes(&self) -> Zeroizing<[u8; 32]> {
+        let mut bytes = Zeroizing::new([0u8; 32]);
+        let ctx = Context::default();
+        unsafe {
+            let _ = secp256k1_keypair_sec(ctx.context, bytes.as_mut_ptr(), &self.key);
+        }
+        bytes
+    }
+
+    /// Return the public key of this keypair
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+
+    /// Return the x-only public key of this keypair, with the parity of the full public key
+    pub fn xonly_public_key(&self) -> XOnlyPublicKey {
+        XOnlyPublicKey::from(self)
+    }
+
+    /// Return the keypair for the secret key self + tweak, failing if that is zero
+    pub fn tweak_add(&self, tweak: &Scalar) -> Result<Self, Error> {
+        let mut bytes = self.secret_bytes();
+        let ctx = Context::default();
         if unsafe {
-            secp256k1_keypair_create(ctx.context, &mut pub_key.key, sec_key.to_bytes().as_ptr())
+            secp256k1_ec_seckey_tweak_add(
+                ctx.context,
+                bytes.as_mut_ptr(),
+                tweak.to_bytes().as_ptr(),
+            )
         } == 0
         {
-            return Err(Error::InvalidSecretKey);
+            return Err(Error::InvalidTweak);
         }
-        Ok(pub_key)
+        Self::from_secret_bytes(&bytes)
+    }
+
+    /// Return the keypair for the secret key tweak*self, failing if the tweak is zero
+    pub fn tweak_mul(&self, tweak: &Scalar) -> Result<Self, Error> {
+        let mut bytes = self.secret_bytes();
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_ec_seckey_tweak_mul(
+                ctx.context,
+                bytes.as_mut_ptr(),
+                tweak.to_bytes().as_ptr(),
+            )
+        } == 0
+        {
+            return Err(Error::InvalidTweak);
+        }
+        Self::from_secret_bytes(&bytes)
+    }
+
+    /// Return the keypair for the secret key -self
+    pub fn negate(&self) -> Self {
+        let mut bytes = self.secret_bytes();
+        let ctx = Context::default();
+        unsafe {
+            // the secret key of a keypair is never zero, so this can't fail
+            let _ = secp256k1_ec_seckey_negate(ctx.context, bytes.as_mut_ptr());
+        }
+        Self::from_secret_bytes(&bytes).unwrap()
+    }
+
+    /// Return the keypair whose x-only public key is this one's tweaked by tweak, negating the secret key first if needed as BIP341 requires; fails if the tweak is not less than the group order or the result is invalid
+    pub fn xonly_tweak_add(&self, tweak: &[u8; 32]) -> Result<Self, Error> {
+        let mut key_pair = self.clone();
+        let ctx = Context::default();
+        if unsafe {
+            secp256k1_keypair_xonly_tweak_add(ctx.context, &mut key_pair.key, tweak.as_ptr())
+        } == 0
+        {
+            return Err(Error::InvalidTweak);
+        }
+        Ok(key_pair)
+    }
+
+    /// Encode the secret key in Wallet Import Format for network, flagging whether the compressed public key should be used
+    pub fn to_wif(&self, network: Network, compressed: bool) -> String {
+        let mut data = Vec::with_capacity(34);
+        data.push(network.wif_version());
+        data.extend_from_slice(self.secret_bytes().as_ref());
+        if compressed {
+            data.push(0x01);
+        }
+
+        let s = base58::encode_check(&data);
+        data.zeroize();
+        s
+    }
+
+    /// Decode a keypair from Wallet Import Format, returning it with its network and whether it is flagged as compressed
+    pub fn from_wif(s: &str) -> Result<(Self, Network, bool), Error> {
+        let mut data = match base58::decode_check(s) {
+            Ok(data) => data,
+            Err(e) => return Err(Error::Conversion(ConversionError::Base58(e))),
+        };
+
+        let r = Self::parse_wif(&data);
+        data.zeroize();
+        r
+    }
+
+    fn parse_wif(data: &[u8]) -> Result<(Self, Network, bool), Error> {
+        let compressed = match data.len() {
+            33 => false,
+            34 if data[33] == 0x01 => true,
+            34 => return Err(Error::InvalidWif),
+            n => return Err(Error::Conversion(ConversionError::WrongNumberOfBytes(n))),
+        };
+        let network = Network::from_wif_version(data[0]).ok_or(Error::InvalidWif)?;
+        let key_pair = Self::try_from(&data[1..33])?;
+
+        Ok((key_pair, network, compressed))
+    }
+
+    /// Encode the keypair as a SEC1 ECPrivateKey in DER
+    pub fn to_sec1_der(&self) -> Zeroizing<Vec<u8>> {
+        pkcs::sec1_encode(self)
+    }
+
+    /// Decode a keypair from a SEC1 ECPrivateKey in DER
+    pub fn from_sec1_der(der: &[u8]) -> Result<Self, pkcs::Error> {
+        pkcs::sec1_decode(der)
+    }
+
+    /// Encode the keypair as a SEC1 `EC PRIVATE KEY` PEM block
+    pub fn to_sec1_pem(&self) -> Zeroizing<String> {
+        pkcs::pem_encode_secret(pkcs::SEC1_LABEL, &self.to_sec1_der())
+    }
+
+    /// Decode a keypair from the first SEC1 `EC PRIVATE KEY` PEM block, skipping any others such as `EC PARAMETERS`
+    pub fn from_sec1_pem(pem: &str) -> Result<Self, pkcs::Error> {
+        Self::from_sec1_der(&pkcs::pem_decode_secret(pkcs::SEC1_LABEL, pem)?)
+    }
+
+    /// Encode the keypair as a PKCS#8 PrivateKeyInfo in DER
+    pub fn to_pkcs8_der(&self) -> Zeroizing<Vec<u8>> {
+        pkcs::pkcs8_encode(self)
+    }
+
+    /// Decode a keypair from an unencrypted PKCS#8 PrivateKeyInfo in DER
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, pkcs::Error> {
+        pkcs::pkcs8_decode(der)
+    }
+
+    /// Encode the keypair as a PKCS#8 `PRIVATE KEY` PEM block
+    pub fn to_pkcs8_pem(&self) -> Zeroizing<String> {
+        pkcs::pem_encode_secret(pkcs::PKCS8_LABEL, &self.to_pkcs8_der())
+    }
+
+    /// Decode a keypair from the first PKCS#8 `PRIVATE KEY` PEM block
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Self, pkcs::Error> {
+        Self::from_pkcs8_der(&pkcs::pem_decode_secret(pkcs::PKCS8_LABEL, pem)?)
+    }
+
+    /// Return the private JSON Web Key of this keypair, which holds the secret key as its d member
+    pub fn to_jwk(&self) -> Jwk {
+        Jwk::from_key_pair(self)
+    }
+
+    /// Return the keypair of a private JSON Web Key, checking that its secret and public keys match
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.key_pair()
     }
 }
 
@@ -445,14 +865,39 @@ impl TryFrom<&Scalar> for KeyPair {
     }
 }
 
+impl TryFrom<&[u8]> for KeyPair {
+    type Error = Error;
+    /// Create a keypair from the passed secret key bytes
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(<[u8; 32]>::try_from(input)?);
+        Self::from_secret_bytes(&bytes)
+    }
+}
+
 impl Debug for KeyPair {
+    /// Print only the public key, so the secret key can't leak into logs
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("KeyPair")
-            .field("data", &bs58::encode(self.key.data).into_string())
+            .field("public_key", &self.public_key())
+            .field("secret_key", &"<redacted>")
             .finish()
     }
 }
 
+impl Zeroize for KeyPair {
+    fn zeroize(&mut self) {
+        self.key.data.zeroize();
+    }
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for KeyPair {}
+
 impl PartialEq for KeyPair {
     fn eq(&self, other: &Self) -> bool {
         let p1: PublicKey = self.into();
@@ -486,19 +931,6 @@ impl Hash for KeyPair {
     }
 }
 
-impl From<&KeyPair> for Scalar {
-    fn from(kp: &KeyPair) -> Scalar {
-        let mut bytes = [0u8; 32];
-        let ctx = Context::default();
-
-        unsafe {
-            let _ = secp256k1_keypair_sec(ctx.context, bytes.as_mut_ptr(), &kp.key);
-        }
-
-        Scalar::from(bytes)
-    }
-}
-
 impl From<&KeyPair> for PublicKey {
     fn from(kp: &KeyPair) -> PublicKey {
         let mut key = secp256k1_pubkey { data: [0; 64] };
@@ -529,12 +961,100 @@ impl From<&KeyPair> for XOnlyPublicKey {
     }
 }
 
+impl StringFormats for KeyPair {
+    type Bytes = Zeroizing<[u8; 32]>;
+    type Error = Error;
+
+    const DESCRIPTION: &'static str = "a secp256k1 secret key";
+
+    fn encode_bytes(&self) -> Zeroizing<[u8; 32]> {
+        self.secret_bytes()
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        KeyPair::try_from(bytes)
+    }
+}
+
+impl FixedBytes<32> for KeyPair {
+    fn to_fixed_bytes(&self) -> [u8; 32] {
+        *self.secret_bytes()
+    }
+}
+
+/// Opt-in serde support for KeyPair, which serializes the secret key bytes; use it as `#[serde(with = "p256k1::keys::keypair_secret")]`
+pub mod keypair_secret {
+    use super::*;
+
+    /// Serialize the keypair as its 32 secret key bytes, or as hex for human readable formats, the same as a Scalar
+    pub fn serialize<S>(key_pair: &KeyPair, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        traits::serialize(key_pair, serializer)
+    }
+
+    /// Deserialize a keypair from its 32 secret key bytes, or from hex for human readable formats
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<KeyPair, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        traits::deserialize(deserializer)
+    }
+}
+
+/// Compute the BIP324 x-only ECDH shared secret from two ElligatorSwift encoded public keys, where initiator is true if sec_key belongs to ell_a64 and false if it belongs to ell_b64
+pub fn ellswift_xdh(
+    ell_a64: &[u8; 64],
+    ell_b64: &[u8; 64],
+    sec_key: &Scalar,
+    initiator: bool,
+) -> Result<[u8; 32], Error> {
+    let ctx = Context::default();
+    let mut shared = [0u8; 32];
+    if unsafe {
+        secp256k1_ellswift_xdh(
+            ctx.context,
+            shared.as_mut_ptr(),
+            ell_a64.as_ptr(),
+            ell_b64.as_ptr(),
+            sec_key.to_bytes().as_ptr(),
+            if initiator { 0 } else { 1 },
+            secp256k1_ellswift_xdh_hash_function_bip324,
+            core::ptr::null_mut(),
+        )
+    } == 0
+    {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    Ok(shared)
+}
+
+/// Compute the BIP340 tagged hash SHA256(SHA256(tag) || SHA256(tag) || msg)
+pub fn tagged_hash(tag: &[u8], msg: &[u8]) -> [u8; 32] {
+    let ctx = Context::default();
+    let mut hash = [0u8; 32];
+    unsafe {
+        secp256k1_tagged_sha256(
+            ctx.context,
+            hash.as_mut_ptr(),
+            tag.as_ptr(),
+            tag.len(),
+            msg.as_ptr(),
+            msg.len(),
+        );
+    }
+
+    hash
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
     use super::*;
-    use crate::point::Point;
+    use crate::field;
     use rand_core::OsRng;
 
     #[test]
@@ -594,7 +1114,7 @@ mod tests {
         let xopubkey = XOnlyPublicKey::try_from(&point.x().to_bytes()[..]).unwrap();
         let xopubkey2 = XOnlyPublicKey::new(&scalar).unwrap();
         let xopubkey3 = XOnlyPublicKey::from(&keypair);
-        let seckey = Scalar::from(&keypair);
+        let seckey = Scalar::from(*keypair.secret_bytes());
         let pubkey = PublicKey::from(&keypair);
         let pubkey2 = PublicKey::new(&scalar).unwrap();
         let pubkey3 = PublicKey::new(&seckey).unwrap();
@@ -623,6 +1143,17 @@ mod tests {
         let xodeser: XOnlyPublicKey = serde_json::from_str(&xoser).expect("failed to deserialize");
 
         assert_eq!(xonly_public_key, xodeser);
+
+        let b = bincode::serialize(&public_key).expect("failed to serialize");
+        assert_eq!(b.len(), 8 + 33);
+        assert_eq!(bincode::deserialize::<PublicKey>(&b).unwrap(), public_key);
+
+        let b = bincode::serialize(&xonly_public_key).expect("failed to serialize");
+        assert_eq!(b.len(), 8 + 32);
+        assert_eq!(
+            bincode::deserialize::<XOnlyPublicKey>(&b).unwrap(),
+            xonly_public_key
+        );
     }
 
     #[test]
@@ -655,6 +1186,428 @@ mod tests {
         sort_test::<KeyPair>();
     }
 
+    #[test]
+    fn from_point() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0xff {
+            let x = Scalar::random(&mut rng);
+            let p = Point::from(x) + Point::G();
+            let expected = PublicKey::new(&(x + Scalar::from(1))).unwrap();
+
+            let key = PublicKey::from_point(&p).unwrap();
+            assert_eq!(key, expected);
+            assert_eq!(key.to_bytes(), p.compress().data);
+            assert_eq!(PublicKey::try_from(p).unwrap(), expected);
+            assert_eq!(Point::from(&key), p);
+
+            let xonly = XOnlyPublicKey::from_point(&p).unwrap();
+            let expected = XOnlyPublicKey::from(&expected);
+            assert_eq!(xonly, expected);
+            assert_eq!(xonly.parity, expected.parity);
+            assert_eq!(XOnlyPublicKey::try_from(p).unwrap(), expected);
+            assert_eq!(Point::from(&xonly), p.to_even_y().0);
+        }
+
+        assert!(PublicKey::try_from(Point::identity()).is_err());
+        assert!(XOnlyPublicKey::try_from(Point::identity()).is_err());
+
+        let g = Point::G();
+        let off_curve = Point::from_affine_unchecked(&g.x(), &(g.y() + field::Element::from(1)));
+        assert!(matches!(
+            PublicKey::from_point(&off_curve),
+            Err(Error::InvalidPublicKey)
+        ));
+        assert!(matches!(
+            XOnlyPublicKey::from_point(&off_curve),
+            Err(Error::InvalidXOnlyPublicKey)
+        ));
+    }
+
+    #[test]
+    fn tweak() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0x40 {
+            let x = Scalar::random(&mut rng);
+            let t = Scalar::random(&mut rng);
+            let key = PublicKey::new(&x).unwrap();
+
+            assert_eq!(
+                key.tweak_add(&t).unwrap(),
+                PublicKey::new(&(x + t)).unwrap()
+            );
+            assert_eq!(
+                key.tweak_mul(&t).unwrap(),
+                PublicKey::new(&(x * t)).unwrap()
+            );
+            assert_eq!(key.negate(), PublicKey::new(&-x).unwrap());
+            assert_eq!(key.negate().negate(), key);
+
+            let key_pair = KeyPair::new(&x).unwrap();
+            assert_eq!(
+                key_pair.tweak_add(&t).unwrap(),
+                KeyPair::new(&(x + t)).unwrap()
+            );
+            assert_eq!(
+                key_pair.tweak_mul(&t).unwrap(),
+                KeyPair::new(&(x * t)).unwrap()
+            );
+            assert_eq!(key_pair.negate(), KeyPair::new(&-x).unwrap());
+            assert_eq!(key_pair.negate().public_key(), key.negate());
+        }
+
+        let x = Scalar::random(&mut rng);
+        let key = PublicKey::new(&x).unwrap();
+        let zero = Scalar::from(0);
+
+        assert!(matches!(key.tweak_add(&-x), Err(Error::InvalidTweak)));
+        assert!(matches!(key.tweak_mul(&zero), Err(Error::InvalidTweak)));
+        assert_eq!(key.tweak_add(&zero).unwrap(), key);
+        let key_pair = KeyPair::new(&x).unwrap();
+        assert!(matches!(key_pair.tweak_add(&-x), Err(Error::InvalidTweak)));
+        assert!(matches!(
+            key_pair.tweak_mul(&zero),
+            Err(Error::InvalidTweak)
+        ));
+        assert_eq!(key_pair.tweak_add(&zero).unwrap(), key_pair);
+    }
+
+    #[test]
+    fn xonly_tweak() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0x40 {
+            let x = Scalar::random(&mut rng);
+            let t = Scalar::random(&mut rng);
+            let tweak = t.to_bytes();
+            let internal = XOnlyPublicKey::new(&x).unwrap();
+
+            let (tweaked, parity) = internal.tweak_add(&tweak).unwrap();
+            let q = Point::from(&internal) + Point::from(t);
+            assert_eq!(tweaked, XOnlyPublicKey::from_point(&q).unwrap());
+            assert_eq!(parity, q.to_even_y().1);
+            assert_eq!(tweaked.parity(), parity);
+
+            assert!(internal.tweak_add_check(&tweaked, parity, &tweak));
+            assert!(!internal.tweak_add_check(&tweaked, Parity::from(!parity.is_odd()), &tweak));
+            assert!(!internal.tweak_add_check(&internal, parity, &tweak));
+
+            let key_pair = KeyPair::new(&x).unwrap().xonly_tweak_add(&tweak).unwrap();
+            assert_eq!(XOnlyPublicKey::from(&key_pair), tweaked);
+            assert_eq!(XOnlyPublicKey::from(&key_pair).parity(), parity);
+        }
+
+        let internal = XOnlyPublicKey::new(&Scalar::from(1)).unwrap();
+        assert!(matches!(
+            internal.tweak_add(&[0xff; 32]),
+            Err(Error::InvalidTweak)
+        ));
+        assert_eq!(internal.parity(), Parity::Even);
+        assert!(matches!(
+            KeyPair::new(&Scalar::from(1))
+                .unwrap()
+                .xonly_tweak_add(&[0xff; 32]),
+            Err(Error::InvalidTweak)
+        ));
+    }
+
+    #[test]
+    fn combine() {
+        let mut rng = OsRng::default();
+        let xs: Vec<Scalar> = (0..0x10).map(|_| Scalar::random(&mut rng)).collect();
+        let keys: Vec<PublicKey> = xs.iter().map(|x| PublicKey::new(x).unwrap()).collect();
+        let sum = xs.iter().fold(Scalar::from(0), |acc, x| acc + x);
+
+        assert_eq!(
+            PublicKey::combine(&keys).unwrap(),
+            PublicKey::new(&sum).unwrap()
+        );
+        assert_eq!(PublicKey::combine(&keys[..1]).unwrap(), keys[0]);
+        assert!(matches!(
+            PublicKey::combine(&[]),
+            Err(Error::InvalidCombination)
+        ));
+        assert!(matches!(
+            PublicKey::combine(&[keys[0], keys[0].negate()]),
+            Err(Error::InvalidCombination)
+        ));
+    }
+
+    #[test]
+    fn ellswift_xdh_agree() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0x10 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let ell_a = Point::from(a).to_ellswift(&mut rng).unwrap();
+            let ell_b = Point::from(b).to_ellswift(&mut rng).unwrap();
+
+            let sa = ellswift_xdh(&ell_a, &ell_b, &a, true).unwrap();
+            let sb = ellswift_xdh(&ell_a, &ell_b, &b, false).unwrap();
+            assert_eq!(sa, sb);
+
+            let swapped = ellswift_xdh(&ell_b, &ell_a, &a, false).unwrap();
+            assert_ne!(sa, swapped);
+        }
+
+        let ell = Point::G().to_ellswift(&mut rng).unwrap();
+        assert!(ellswift_xdh(&ell, &ell, &Scalar::from(0), true).is_err());
+    }
+
+    #[test]
+    fn tagged() {
+        for (tag, msg) in [
+            (&b"TapTweak"[..], &[7u8; 32][..]),
+            (b"", b""),
+            (&[0xAB; 100], b"m"),
+        ] {
+            let t = Sha256::digest(tag);
+            let expected = Sha256::new()
+                .chain_update(t)
+                .chain_update(t)
+                .chain_update(msg)
+                .finalize();
+
+            assert_eq!(tagged_hash(tag, msg)[..], expected[..]);
+        }
+    }
+
+    #[test]
+    fn ellswift_xdh_bip324() {
+        // (priv_ours, ellswift_ours, ellswift_theirs, initiating, shared_secret) vectors from BIP324
+        let vectors = [
+            (
+                "61062ea5071d800bbfd59e2e8b53d47d194b095ae5a4df04936b49772ef0d4d7",
+                "ec0adff257bbfe500c188c80b4fdd640f6b45a482bbc15fc7cef5931deff0aa186f6eb9bba7b85dc4dcc28b28722de1e3d9108b985e2967045668f66098e475b",
+                "a4a94dfce69b4a2a0a099313d10f9f7e7d649d60501c9e1d274c300e0d89aafaffffffffffffffffffffffffffffffffffffffffffffffffffffffff8faf88d5",
+                true,
+                "c6992a117f5edbea70c3f511d32d26b9798be4b81a62eaee1a5acaa8459a3592",
+            ),
+            (
+                "1f9c581b35231838f0f17cf0c979835baccb7f3abbbb96ffcc318ab71e6e126f",
+                "a1855e10e94e00baa23041d916e259f7044e491da6171269694763f018c7e63693d29575dcb464ac816baa1be353ba12e3876cba7628bd0bd8e755e721eb0140",
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0000000000000000000000000000000000000000000000000000000000000000",
+                false,
+                "a0138f564f74d0ad70bc337dacc9d0bf1d2349364caf1188a1e6e8ddb3b7b184",
+            ),
+            (
+                "0286c41cd30913db0fdff7a64ebda5c8e3e7cef10f2aebc00a7650443cf4c60d",
+                "d1ee8a93a01130cbf299249a258f94feb5f469e7d0f2f28f69ee5e9aa8f9b54a60f2c3ff2d023634ec7f4127a96cc11662e402894cf1f694fb9a7eaa5f1d9244",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffff22d5e441524d571a52b3def126189d3f416890a99d4da6ede2b0cde1760ce2c3f98457ae",
+                true,
+                "250b93570d411149105ab8cb0bc5079914906306368c23e9d77c2a33265b994c",
+            ),
+            (
+                "6c77432d1fda31e9f942f8af44607e10f3ad38a65f8a4bddae823e5eff90dc38",
+                "d2685070c1e6376e633e825296634fd461fa9e5bdf2109bcebd735e5a91f3e587c5cb782abb797fbf6bb5074fd1542a474f2a45b673763ec2db7fb99b737bbb9",
+                "56bd0c06f10352c3a1a9f4b4c92f6fa2b26df124b57878353c1fc691c51abea77c8817daeeb9fa546b77c8daf79d89b22b0e1b87574ece42371f00237aa9d83a",
+                false,
+                "1918b741ef5f9d1d7670b050c152b4a4ead2c31be9aecb0681c0cd4324150853",
+            ),
+            (
+                "a6ec25127ca1aa4cf16b20084ba1e6516baae4d32422288e9b36d8bddd2de35a",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffff053d7ecca53e33e185a8b9be4e7699a97c6ff4c795522e5918ab7cd6b6884f67e683f3dc",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffa7730be30000000000000000000000000000000000000000000000000000000000000000",
+                true,
+                "dd210aa6629f20bb328e5d89daa6eb2ac3d1c658a725536ff154f31b536c23b2",
+            ),
+            (
+                "0af952659ed76f80f585966b95ab6e6fd68654672827878684c8b547b1b94f5a",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffc81017fd92fd31637c26c906b42092e11cc0d3afae8d9019d2578af22735ce7bc469c72d",
+                "9652d78baefc028cd37a6a92625b8b8f85fde1e4c944ad3f20e198bef8c02f19fffffffffffffffffffffffffffffffffffffffffffffffffffffffff2e91870",
+                false,
+                "3568f2aea2e14ef4ee4a3c2a8b8d31bc5e3187ba86db10739b4ff8ec92ff6655",
+            ),
+            (
+                "f90e080c64b05824c5a24b2501d5aeaf08af3872ee860aa80bdcd430f7b63494",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffff115173765dc202cf029ad3f15479735d57697af12b0131dd21430d5772e4ef11474d58b9",
+                "12a50f3fafea7c1eeada4cf8d33777704b77361453afc83bda91eef349ae044d20126c6200547ea5a6911776c05dee2a7f1a9ba7dfbabbbd273c3ef29ef46e46",
+                true,
+                "e25461fb0e4c162e18123ecde88342d54d449631e9b75a266fd9260c2bb2f41d",
+            ),
+        ];
+
+        for (priv_ours, ellswift_ours, ellswift_theirs, initiating, shared_secret) in vectors {
+            let sec_key = Scalar::try_from(hex::decode(priv_ours).unwrap().as_slice()).unwrap();
+            let ours: [u8; 64] = hex::decode(ellswift_ours).unwrap().try_into().unwrap();
+            let theirs: [u8; 64] = hex::decode(ellswift_theirs).unwrap().try_into().unwrap();
+
+            let (ell_a, ell_b) = if initiating {
+                (&ours, &theirs)
+            } else {
+                (&theirs, &ours)
+            };
+            let shared = ellswift_xdh(ell_a, ell_b, &sec_key, initiating).unwrap();
+
+            assert_eq!(hex::encode(shared), shared_secret);
+        }
+    }
+
+    #[test]
+    fn keypair_secret() {
+        let mut rng = OsRng::default();
+        let x = Scalar::random(&mut rng);
+        let key_pair = KeyPair::new(&x).unwrap();
+
+        assert_eq!(*key_pair.secret_bytes(), x.to_bytes());
+        assert_eq!(KeyPair::from_secret_bytes(&x.to_bytes()).unwrap(), key_pair);
+        assert_eq!(KeyPair::try_from(&x.to_bytes()[..]).unwrap(), key_pair);
+        assert_eq!(key_pair.public_key(), PublicKey::new(&x).unwrap());
+        assert_eq!(
+            key_pair.xonly_public_key(),
+            XOnlyPublicKey::new(&x).unwrap()
+        );
+        assert_eq!(
+            key_pair.xonly_public_key().parity(),
+            Point::from(x).to_even_y().1
+        );
+
+        assert!(KeyPair::from_secret_bytes(&[0; 32]).is_err());
+        assert!(KeyPair::from_secret_bytes(&[0xff; 32]).is_err());
+        assert!(KeyPair::try_from(&[1u8; 31][..]).is_err());
+
+        let debug = format!("{:?}", key_pair);
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&bs58::encode(x.to_bytes()).into_string()));
+        assert!(!debug.contains(&hex::encode(x.to_bytes())));
+
+        let mut zeroed = key_pair.clone();
+        zeroed.zeroize();
+        assert!(zeroed.key.data.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn keypair_serde() {
+        #[derive(Serialize, Deserialize)]
+        struct Wallet {
+            #[serde(with = "keypair_secret")]
+            key_pair: KeyPair,
+        }
+
+        let mut rng = OsRng::default();
+        let x = Scalar::random(&mut rng);
+        let wallet = Wallet {
+            key_pair: KeyPair::new(&x).unwrap(),
+        };
+
+        let json = serde_json::to_string(&wallet).expect("failed to serialize");
+        let w: Wallet = serde_json::from_str(&json).expect("failed to deserialize");
+        assert_eq!(w.key_pair, wallet.key_pair);
+        assert_eq!(*w.key_pair.secret_bytes(), x.to_bytes());
+
+        assert!(serde_json::from_str::<Wallet>("{\"key_pair\":[0]}").is_err());
+
+        // the wire format matches that of the secret key as a Scalar
+        #[derive(Serialize)]
+        struct Secret {
+            key_pair: Scalar,
+        }
+        let secret = Secret { key_pair: x };
+        assert_eq!(json, serde_json::to_string(&secret).unwrap());
+        let b = bincode::serialize(&wallet).expect("failed to serialize");
+        assert_eq!(b, bincode::serialize(&secret).unwrap());
+        let w: Wallet = bincode::deserialize(&b).expect("failed to deserialize");
+        assert_eq!(w.key_pair, wallet.key_pair);
+    }
+
+    #[test]
+    fn wif() {
+        let bytes: [u8; 32] =
+            hex::decode("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let key_pair = KeyPair::from_secret_bytes(&bytes).unwrap();
+
+        let vectors = [
+            (
+                "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+                Network::Mainnet,
+                false,
+            ),
+            (
+                "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617",
+                Network::Mainnet,
+                true,
+            ),
+            (
+                "91gGn1HgSap6CbU12F6z3pJri26xzp7Ay1VW6NHCoEayNXwRpu2",
+                Network::Testnet,
+                false,
+            ),
+            (
+                "cMzLdeGd5vEqxB8B6VFQoRopQ3sLAAvEzDAoQgvX54xwofSWj1fx",
+                Network::Testnet,
+                true,
+            ),
+        ];
+
+        for (wif, network, compressed) in vectors {
+            assert_eq!(key_pair.to_wif(network, compressed), wif);
+            let (decoded, decoded_network, decoded_compressed) = KeyPair::from_wif(wif).unwrap();
+            assert_eq!(*decoded.secret_bytes(), bytes);
+            assert_eq!(decoded_network, network);
+            assert_eq!(decoded_compressed, compressed);
+        }
+
+        assert!(matches!(
+            KeyPair::from_wif("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98618"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        // compression flag of 0x02
+        assert!(matches!(
+            KeyPair::from_wif("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvWxyf5d"),
+            Err(Error::InvalidWif)
+        ));
+        // version byte of 0x42
+        assert!(matches!(
+            KeyPair::from_wif("3DPVFteA1yousfvzBxj83LatHHZjXzKgqSEstTH7psRszwTfrmw"),
+            Err(Error::InvalidWif)
+        ));
+    }
+
+    #[test]
+    fn string_formats() {
+        let hex = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let pubkey = PublicKey::new(&Scalar::from(1)).unwrap();
+        let xonly = XOnlyPublicKey::new(&Scalar::from(1)).unwrap();
+
+        assert_eq!(pubkey.to_hex(), format!("02{}", hex));
+        assert_eq!(PublicKey::from_hex(&pubkey.to_hex()).unwrap(), pubkey);
+        assert_eq!(
+            PublicKey::from_base58check(&pubkey.to_base58check()).unwrap(),
+            pubkey
+        );
+        assert_eq!(PublicKey::from_str(&pubkey.to_string()).unwrap(), pubkey);
+
+        assert_eq!(xonly.to_hex(), hex);
+        assert_eq!(
+            xonly.to_base58check(),
+            "vcnHPXdBgyWQ96wJGRKNyc5ksFffAFkKWwq2zcGv32w2RcLxN"
+        );
+        assert_eq!(XOnlyPublicKey::from_hex(hex).unwrap(), xonly);
+        assert_eq!(
+            XOnlyPublicKey::from_base58check(&xonly.to_base58check()).unwrap(),
+            xonly
+        );
+        assert_eq!(XOnlyPublicKey::from_str(&xonly.to_string()).unwrap(), xonly);
+
+        assert!(matches!(
+            XOnlyPublicKey::from_base58check("vcnHPXdBgyWQ96wJGRKNyc5ksFffAFkKWwq2zcGv32w2RcLxM"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        assert!(matches!(
+            PublicKey::from_hex("02zz"),
+            Err(Error::Conversion(ConversionError::Hex))
+        ));
+    }
+
     fn hash_test<K>()
     where
         K: for<'a> TryFrom<&'a Scalar> + Hash + Eq,
@@ -20,18 +20,42 @@ mod bindings;
 #[allow(unused_imports)]
 mod _rename;
 
+/// Bitcoin address encoding and parsing
+pub mod address;
+
+/// Stacks c32check encoding and addresses
+pub mod c32;
+
 /// secp256k1 context operations
 pub mod context;
 
 /// ECDSA operations
 pub mod ecdsa;
 
+/// ElligatorSwift encoding of points as specified in BIP324
+pub mod ellswift;
+
 /// Errors
 pub mod errors;
 
+/// Ethereum addresses and EIP-55 checksums
+pub mod eth;
+
+/// JSON Web Key encoding of secp256k1 keys and RFC 7638 thumbprints
+pub mod jwk;
+
 /// Key operations on the secp256k1 curve
 pub mod keys;
 
+/// The Bitcoin networks which keys, seeds and addresses are encoded for
+pub mod network;
+
+/// BIP39 mnemonic encoding of entropy and seed derivation
+pub mod mnemonic;
+
+/// SEC1, PKCS#8 and SubjectPublicKeyInfo DER and PEM encodings of keys
+pub mod pkcs;
+
 /// Point operations on the secp256k1 curve
 pub mod point;
 
@@ -41,10 +65,27 @@ pub mod scalar;
 /// Schnorr proofs
 pub mod schnorr;
 
+/// Opt-in serde adapters which encode values as base58 strings
+pub mod serde_base58;
+
 /// Field elements arithmetic
 pub mod field;
 
+/// BIP32 hierarchical deterministic key derivation
+pub mod hd;
+
+/// Nothing-up-my-sleeve generator derivation
+pub mod generators;
+
+/// Hashing to the secp256k1 curve as specified in RFC 9380
+pub mod hash_to_curve;
+
 /// Traits
 pub mod traits;
 
+/// Minimal DER reading and writing and PEM armoring
+pub mod asn1;
+
+mod base58;
+
 mod group;
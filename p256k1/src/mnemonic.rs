@@ -0,0 +1,409 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use pbkdf2::pbkdf2_hmac;
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    hd::{self, ExtendedPrivateKey},
+    keys::KeyPair,
+    network::Network,
+};
+
+/// The BIP39 English wordlist, one word per line in sorted order
+const ENGLISH: &str = include_str!("bip39_english.txt");
+
+/// The number of PBKDF2 iterations used to stretch a mnemonic into a seed
+const PBKDF2_ROUNDS: u32 = 2048;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors in BIP39 mnemonic operations
+pub enum Error {
+    /// The entropy was not 16, 20, 24, 28 or 32 bytes long
+    InvalidEntropyLength(usize),
+    /// The mnemonic did not have 12, 15, 18, 21 or 24 words
+    InvalidWordCount(usize),
+    /// The mnemonic contained a word which is not in the wordlist
+    UnknownWord(String),
+    /// The mnemonic checksum did not match its entropy
+    InvalidChecksum,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Return the BIP39 English wordlist
+pub fn english() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    WORDS.get_or_init(|| ENGLISH.lines().collect())
+}
+
+#[derive(Clone)]
+/// A BIP39 mnemonic, which holds its entropy and is zeroed when dropped
+pub struct Mnemonic {
+    entropy: Vec<u8>,
+}
+
+impl Mnemonic {
+    /// Construct a mnemonic from 16, 20, 24, 28 or 32 bytes of entropy
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
+        match entropy.len() {
+            16 | 20 | 24 | 28 | 32 => Ok(Self {
+                entropy: entropy.to_vec(),
+            }),
+            n => Err(Error::InvalidEntropyLength(n)),
+        }
+    }
+
+    /// Generate a random mnemonic with 12, 15, 18, 21 or 24 words
+    pub fn generate<T: RngCore + CryptoRng>(rng: &mut T, word_count: usize) -> Result<Self, Error> {
+        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+            return Err(Error::InvalidWordCount(word_count));
+        }
+
+        let mut entropy = vec![0u8; word_count / 3 * 4];
+        rng.fill_bytes(&mut entropy);
+
+        let m = Self::from_entropy(&entropy);
+        entropy.zeroize();
+        m
+    }
+
+    /// Return the entropy encoded by this mnemonic
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Return the words of this mnemonic
+    pub fn words(&self) -> Vec<&'static str> {
+        let words = english();
+        let checksum_bits = self.entropy.len() / 4;
+        let hash = Sha256::digest(&self.entropy);
+
+        let mut bits = Vec::with_capacity(self.entropy.len() * 8 + checksum_bits);
+        for byte in self.entropy.iter().chain(hash.iter()) {
+            for i in (0..8).rev() {
+                bits.push((byte >> i) & 1 == 1);
+            }
+        }
+        bits.truncate(self.entropy.len() * 8 + checksum_bits);
+
+        let r = bits
+            .chunks(11)
+            .map(|chunk| words[chunk.iter().fold(0, |acc, b| acc << 1 | *b as usize)])
+            .collect();
+        bits.zeroize();
+        r
+    }
+
+    /// Derive the 64 byte BIP39 seed using PBKDF2-HMAC-SHA512, with the mnemonic and passphrase NFKD normalized first as BIP39 requires
+    pub fn to_seed(&self, passphrase: &str) -> Seed {
+        let mut words = self.to_string();
+        let mut phrase: String = words.nfkd().collect();
+        let mut salt: String = "mnemonic".chars().chain(passphrase.nfkd()).collect();
+        let mut seed = Seed { data: [0; 64] };
+
+        pbkdf2_hmac::<Sha512>(
+            phrase.as_bytes(),
+            salt.as_bytes(),
+            PBKDF2_ROUNDS,
+            &mut seed.data,
+        );
+
+        words.zeroize();
+        phrase.zeroize();
+        salt.zeroize();
+        seed
+    }
+}
+
+impl Display for Mnemonic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.words().join(" "))
+    }
+}
+
+impl Debug for Mnemonic {
+    /// Print only the word count, so the mnemonic can't leak into logs
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Mnemonic")
+            .field("words", &(self.entropy.len() * 3 / 4))
+            .finish()
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Self) -> bool {
+        self.entropy == other.entropy
+    }
+}
+
+impl Eq for Mnemonic {}
+
+impl TryFrom<&str> for Mnemonic {
+    type Error = Error;
+    /// Parse a whitespace separated English mnemonic, validating its checksum
+    fn try_from(s: &str) -> Result<Self, Error> {
+        let words = english();
+        let phrase: Vec<&str> = s.split_whitespace().collect();
+
+        if !matches!(phrase.len(), 12 | 15 | 18 | 21 | 24) {
+            return Err(Error::InvalidWordCount(phrase.len()));
+        }
+
+        let mut bits = Vec::with_capacity(phrase.len() * 11);
+        for word in &phrase {
+            let index = match words.binary_search(word) {
+                Ok(i) => i,
+                Err(_) => return Err(Error::UnknownWord(word.to_string())),
+            };
+            for i in (0..11).rev() {
+                bits.push((index >> i) & 1 == 1);
+            }
+        }
+
+        let checksum_bits = phrase.len() / 3;
+        let entropy_bits = bits.len() - checksum_bits;
+        let mut entropy: Vec<u8> = bits[..entropy_bits]
+            .chunks(8)
+            .map(|chunk| chunk.iter().fold(0, |acc, b| acc << 1 | *b as u8))
+            .collect();
+
+        let hash = Sha256::digest(&entropy);
+        let valid =
+            (0..checksum_bits).all(|i| bits[entropy_bits + i] == ((hash[0] >> (7 - i)) & 1 == 1));
+        bits.zeroize();
+
+        if !valid {
+            entropy.zeroize();
+            return Err(Error::InvalidChecksum);
+        }
+
+        let m = Self::from_entropy(&entropy);
+        entropy.zeroize();
+        m
+    }
+}
+
+impl Zeroize for Mnemonic {
+    fn zeroize(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Mnemonic {}
+
+#[derive(Clone)]
+/// A 64 byte BIP39 seed, which is zeroed when dropped
+pub struct Seed {
+    data: [u8; 64],
+}
+
+impl Seed {
+    /// Return the raw seed bytes
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.data
+    }
+
+    /// Derive the BIP32 master key from this seed
+    pub fn master_key(&self, network: Network) -> Result<ExtendedPrivateKey, hd::Error> {
+        ExtendedPrivateKey::new_master(&self.data, network)
+    }
+
+    /// Derive the keypair at the passed BIP32 path from this seed
+    pub fn key_pair(&self, path: &hd::DerivationPath) -> Result<KeyPair, hd::Error> {
+        Ok(self
+            .master_key(Network::Mainnet)?
+            .derive_path(path)?
+            .key_pair())
+    }
+}
+
+impl Debug for Seed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Seed").field("data", &"<redacted>").finish()
+    }
+}
+
+impl Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Seed {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::Scalar;
+    use rand_core::OsRng;
+
+    #[test]
+    fn wordlist() {
+        let words = english();
+
+        assert_eq!(words.len(), 2048);
+        assert_eq!(words[0], "abandon");
+        assert_eq!(words[2047], "zoo");
+        assert!(words.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn vectors() {
+        // from the reference implementation's vectors, all with passphrase TREZOR
+        let vectors = [
+            (
+                "00000000000000000000000000000000",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+            (
+                "80808080808080808080808080808080",
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+                "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffff",
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+                "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+                "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+            ),
+            (
+                "9e885d952ad362caeb4efe34a8e91bd2",
+                "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+                "274ddc525802f7c828d8ef7ddbcdc5304e87ac3535913611fbbfa986d0c9e5476c91689f9c8a54fd55bd38606aa6a8595ad213d4c9c9f9aca3fb217069a41028",
+            ),
+        ];
+
+        for (entropy, phrase, seed) in vectors {
+            let m = Mnemonic::from_entropy(&hex::decode(entropy).unwrap()).unwrap();
+            assert_eq!(m.to_string(), phrase);
+            assert_eq!(hex::encode(m.to_seed("TREZOR").as_bytes()), seed);
+
+            let parsed = Mnemonic::try_from(phrase).unwrap();
+            assert_eq!(parsed, m);
+            assert_eq!(hex::encode(parsed.entropy()), entropy);
+        }
+    }
+
+    #[test]
+    fn nfkd_passphrase() {
+        // the passphrase of the BIP39 Japanese vectors, which NFKD changes; the seed was computed independently with Python's unicodedata and hashlib
+        let m = Mnemonic::try_from(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let passphrase = "\u{334d}\u{30ac}\u{30d0}\u{30f4}\u{30a1}\u{3071}\u{3070}\u{3050}\u{309e}\u{3061}\u{3062}\u{5341}\u{4eba}\u{5341}\u{8272}";
+        assert_eq!(passphrase, "㍍ガバヴァぱばぐゞちぢ十人十色");
+
+        let seed = m.to_seed(passphrase);
+        assert_eq!(
+            hex::encode(seed.as_bytes()),
+            "ba553eedefe76e67e2602dc20184c564010859faada929a090dd2c57aacb204ceefd15404ab50ef3e8dbeae5195aeae64b0def4d2eead1cdc728a33ced520ffd"
+        );
+
+        // precomposed and decomposed forms of the same passphrase give the same seed
+        let nfkd: String = passphrase.nfkd().collect();
+        assert_ne!(nfkd, passphrase);
+        assert_eq!(m.to_seed(&nfkd).as_bytes(), seed.as_bytes());
+        assert_eq!(
+            m.to_seed("\u{e9}").as_bytes(),
+            m.to_seed("e\u{301}").as_bytes()
+        );
+    }
+
+    #[test]
+    fn generate() {
+        let mut rng = OsRng::default();
+
+        for n in [12, 15, 18, 21, 24] {
+            let m = Mnemonic::generate(&mut rng, n).unwrap();
+            assert_eq!(m.words().len(), n);
+            assert_eq!(Mnemonic::try_from(m.to_string().as_str()).unwrap(), m);
+        }
+
+        assert_eq!(
+            Mnemonic::generate(&mut rng, 13).unwrap_err(),
+            Error::InvalidWordCount(13)
+        );
+        assert_eq!(
+            Mnemonic::from_entropy(&[0; 15]).unwrap_err(),
+            Error::InvalidEntropyLength(15)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let abandon = "abandon ".repeat(11);
+
+        assert_eq!(
+            Mnemonic::try_from(format!("{}abandon", abandon).as_str()).unwrap_err(),
+            Error::InvalidChecksum
+        );
+        assert_eq!(
+            Mnemonic::try_from(format!("{}abandonn", abandon).as_str()).unwrap_err(),
+            Error::UnknownWord("abandonn".to_string())
+        );
+        assert_eq!(
+            Mnemonic::try_from(abandon.as_str()).unwrap_err(),
+            Error::InvalidWordCount(11)
+        );
+        assert!(Mnemonic::try_from(format!("  {}\tabout\n", abandon).as_str()).is_ok());
+
+        let m = Mnemonic::try_from(format!("{}about", abandon).as_str()).unwrap();
+        assert!(!format!("{:?}", m).contains("abandon"));
+        assert!(!format!("{:?}", m.to_seed("")).contains("5eb00bbd"));
+    }
+
+    #[test]
+    fn seed_to_keys() {
+        let m = Mnemonic::try_from(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let seed = m.to_seed("");
+
+        assert_eq!(
+            hex::encode(seed.as_bytes()),
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+        );
+
+        let path = hd::DerivationPath::try_from("m/84'/0'/0'/0/0").unwrap();
+        let key_pair = seed.key_pair(&path).unwrap();
+        let master = seed.master_key(Network::Mainnet).unwrap();
+        let child = master.derive_path(&path).unwrap();
+
+        assert_eq!(key_pair.public_key(), child.public_key());
+        assert_eq!(Scalar::from(*key_pair.secret_bytes()), *child.private_key());
+    }
+}
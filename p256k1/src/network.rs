@@ -0,0 +1,67 @@
+use bech32::Hrp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The network an extended key, WIF secret key or address is encoded for, which selects its version bytes or human readable part
+pub enum Network {
+    /// Bitcoin mainnet, with keys serialized as xprv and xpub and addresses starting 1, 3 or bc1
+    Mainnet,
+    /// Bitcoin testnet, with keys serialized as tprv and tpub and addresses starting m, n, 2 or tb1
+    Testnet,
+    /// Bitcoin signet, which encodes keys and addresses exactly as testnet does
+    Signet,
+    /// Bitcoin regtest, which shares testnet's version bytes but has its own bcrt1 address prefix
+    Regtest,
+}
+
+impl Network {
+    pub(crate) fn private_version(&self) -> [u8; 4] {
+        match self {
+            Network::Mainnet => [0x04, 0x88, 0xAD, 0xE4],
+            _ => [0x04, 0x35, 0x83, 0x94],
+        }
+    }
+
+    pub(crate) fn public_version(&self) -> [u8; 4] {
+        match self {
+            Network::Mainnet => [0x04, 0x88, 0xB2, 0x1E],
+            _ => [0x04, 0x35, 0x87, 0xCF],
+        }
+    }
+
+    pub(crate) fn wif_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            _ => 0xEF,
+        }
+    }
+
+    pub(crate) fn from_wif_version(version: u8) -> Option<Self> {
+        match version {
+            0x80 => Some(Network::Mainnet),
+            0xEF => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn pubkey_hash_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            _ => 0x6F,
+        }
+    }
+
+    pub(crate) fn script_hash_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x05,
+            _ => 0xC4,
+        }
+    }
+
+    pub(crate) fn hrp(&self) -> Hrp {
+        match self {
+            Network::Mainnet => Hrp::parse_unchecked("bc"),
+            Network::Testnet | Network::Signet => Hrp::parse_unchecked("tb"),
+            Network::Regtest => Hrp::parse_unchecked("bcrt"),
+        }
+    }
+}
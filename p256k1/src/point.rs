@@ -1,20 +1,19 @@
 use bs58;
 use core::{
-    cmp::{Eq, PartialEq},
+    cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
     convert::{From, TryFrom},
     ffi::CStr,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     iter::Sum,
     mem,
-    ops::{Add, AddAssign, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Deref, DerefMut, Mul, Neg, Sub},
     slice,
+    str::FromStr,
 };
-use num_traits::Zero;
-use serde::{
-    de::{self, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
+use num_traits::{One, Zero};
+use rand_core::{CryptoRng, RngCore};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::os::raw::c_void;
 
 use crate::_rename::{
@@ -26,15 +25,19 @@ use crate::_rename::{
 use crate::{
     bindings::{
         secp256k1_callback, secp256k1_ecmult_multi_callback, secp256k1_fe, secp256k1_ge,
-        secp256k1_gej, secp256k1_scalar, SECP256K1_TAG_PUBKEY_EVEN, SECP256K1_TAG_PUBKEY_ODD,
+        secp256k1_gej, secp256k1_scalar, SECP256K1_TAG_PUBKEY_EVEN,
+        SECP256K1_TAG_PUBKEY_HYBRID_EVEN, SECP256K1_TAG_PUBKEY_HYBRID_ODD,
+        SECP256K1_TAG_PUBKEY_ODD, SECP256K1_TAG_PUBKEY_UNCOMPRESSED,
     },
     context::Context,
+    ellswift,
     errors::{Base58Error, ConversionError},
-    field,
-    group::secp256k1_ge_set_gej,
-    keys::PublicKey,
+    field, generators,
+    group::{secp256k1_ge_set_gej, secp256k1_pubkey_load},
+    hash_to_curve,
+    keys::{PublicKey, XOnlyPublicKey},
     scalar::Scalar,
-    traits::MultiMult,
+    traits::{self, FixedBytes, MultiMult, StringFormats},
 };
 
 /// The secp256k1 base point
@@ -88,6 +91,46 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<ConversionError> for Error {
+    fn from(e: ConversionError) -> Self {
+        Error::Conversion(e)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The parity of a point's y coordinate, which is what an x-only encoding drops
+pub enum Parity {
+    /// The y coordinate is even
+    Even = 0,
+    /// The y coordinate is odd
+    Odd = 1,
+}
+
+impl Parity {
+    /// Return true if this is odd parity
+    pub fn is_odd(&self) -> bool {
+        *self == Parity::Odd
+    }
+}
+
+impl From<bool> for Parity {
+    /// Convert an is_odd flag into a Parity
+    fn from(odd: bool) -> Self {
+        if odd {
+            Parity::Odd
+        } else {
+            Parity::Even
+        }
+    }
+}
+
+impl From<Parity> for i32 {
+    /// Convert into the integer parity flag used by libsecp256k1
+    fn from(parity: Parity) -> i32 {
+        parity as i32
+    }
+}
+
 #[derive(Copy, Clone)]
 /**
 Point is a wrapper around libsecp256k1's internal secp256k1_gej struct.  It provides a point on the secp256k1 curve in Jacobian coordinates.  This allows for extremely fast curve point operations, and avoids expensive conversions from byte buffers.
@@ -171,18 +214,105 @@ impl Point {
         Point::from(Scalar::from(1))
     }
 
-    /// Convert the point into compressed binary format
-    pub fn compress(&self) -> Compressed {
-        unsafe {
-            let mut ge = secp256k1_ge {
-                x: secp256k1_fe { n: [0; 5] },
-                y: secp256k1_fe { n: [0; 5] },
+    /// Return true if this is the identity point, without doing any field arithmetic
+    pub fn is_identity(&self) -> bool {
+        self.gej.infinity == 1
+    }
+
+    /// Return 2 * self, using the dbl-2009-l formulas for Jacobian coordinates on a curve with a = 0
+    pub fn double(&self) -> Point {
+        if self.is_identity() {
+            return *self;
+        }
+
+        let x = field::Element { fe: self.gej.x };
+        let y = field::Element { fe: self.gej.y };
+        let z = field::Element { fe: self.gej.z };
+
+        let a = x * x;
+        let b = y * y;
+        let c = b * b;
+        let xb = x + b;
+        let d = (xb * xb - a - c) * field::Element::from(2);
+        let e = a * field::Element::from(3);
+        let f = e * e;
+
+        let x3 = f - d - d;
+        let y3 = e * (d - x3) - c * field::Element::from(8);
+        let z3 = y * z * field::Element::from(2);
+
+        Point {
+            gej: secp256k1_gej {
+                x: x3.fe,
+                y: y3.fe,
+                z: z3.fe,
                 infinity: 0,
-            };
+            },
+        }
+    }
+
+    /// Return k * self using double and add, which is much cheaper than a full scalar multiplication for small k
+    pub fn mul_u64(&self, k: u64) -> Point {
+        let mut r = Point::identity();
+
+        for i in (0..(64 - k.leading_zeros())).rev() {
+            r = r.double();
+            if (k >> i) & 1 == 1 {
+                r += self;
+            }
+        }
+
+        r
+    }
+
+    /// Return true if z is exactly 1, so the x and y coordinates are already affine and no field inversion is needed to encode, hash or order this point
+    pub fn is_normalized(&self) -> bool {
+        self.gej.z.n == [1, 0, 0, 0, 0]
+    }
+
+    /// Return an equal point with z = 1 and fully normalized coordinates, so later compression, hashing and ordering are nearly free
+    pub fn canonical(&self) -> Point {
+        if self.is_identity() {
+            return Point::identity();
+        }
+
+        let ge = self.affine();
+        let mut p = Point::new();
+
+        unsafe {
+            secp256k1_gej_set_ge(&mut p.gej, &ge);
+        }
 
-            secp256k1_ge_set_gej(&mut ge, &self.gej);
+        p
+    }
+
+    /// Return the affine coordinates with normalized x and y, skipping the field inversion when z is already 1
+    pub(crate) fn affine(&self) -> secp256k1_ge {
+        let mut ge = secp256k1_ge {
+            x: self.gej.x,
+            y: self.gej.y,
+            infinity: self.gej.infinity,
+        };
+
+        unsafe {
+            if !self.is_normalized() {
+                secp256k1_ge_set_gej(&mut ge, &self.gej);
+            }
             secp256k1_fe_normalize_var(&mut ge.x);
             secp256k1_fe_normalize_var(&mut ge.y);
+        }
+
+        ge
+    }
+
+    /// Convert the point into compressed binary format, encoding the identity as a single zero byte
+    pub fn compress(&self) -> Compressed {
+        if self.is_identity() {
+            return Compressed { data: [0; 33] };
+        }
+
+        unsafe {
+            let ge = self.affine();
 
             let mut c = Compressed { data: [0; 33] };
 
@@ -198,6 +328,104 @@ impl Point {
         }
     }
 
+    /// Convert many points into compressed binary format, sharing a single field inversion across the whole batch
+    pub fn compress_batch(points: &[Point]) -> Vec<Compressed> {
+        let zs: Vec<field::Element> = points
+            .iter()
+            .filter(|p| !p.is_identity() && !p.is_normalized())
+            .map(|p| field::Element { fe: p.gej.z })
+            .collect();
+
+        // Montgomery's trick: invert the product of all z, then peel off each inverse
+        let mut prefix = Vec::with_capacity(zs.len());
+        let mut acc = field::Element::one();
+        for z in &zs {
+            prefix.push(acc);
+            acc *= z;
+        }
+
+        let mut inv = acc.invert();
+        let mut zinvs = vec![field::Element::zero(); zs.len()];
+        for i in (0..zs.len()).rev() {
+            zinvs[i] = inv * prefix[i];
+            inv *= zs[i];
+        }
+
+        let mut zinvs = zinvs.into_iter();
+        points
+            .iter()
+            .map(|p| {
+                if p.is_identity() || p.is_normalized() {
+                    return p.compress();
+                }
+
+                let zinv = zinvs.next().unwrap();
+                let zinv2 = zinv * zinv;
+                let x = field::Element { fe: p.gej.x } * zinv2;
+                let y = field::Element { fe: p.gej.y } * zinv2 * zinv;
+
+                Point::from_affine_unchecked(&x, &y).compress()
+            })
+            .collect()
+    }
+
+    /// Decompress many points, returning the index of the first invalid encoding on failure.  This is a convenience loop over from_sec1 rather than a batched operation: each point needs its own square root and decompressed points are already affine, so there is no inversion to share and no speedup over decoding them one at a time.  The results are normalized, so compressing them again needs no inversion
+    pub fn decompress_batch(compressed: &[Compressed]) -> Result<Vec<Point>, (usize, Error)> {
+        compressed
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Point::from_sec1(c.as_bytes()).map_err(|e| (i, e)))
+            .collect()
+    }
+
+    /// Convert the point into uncompressed binary format, encoding the identity as a single zero byte
+    pub fn uncompressed(&self) -> Uncompressed {
+        if self.is_identity() {
+            return Uncompressed { data: [0; 65] };
+        }
+
+        unsafe {
+            let ge = self.affine();
+
+            let mut u = Uncompressed { data: [0; 65] };
+
+            u.data[0] = SECP256K1_TAG_PUBKEY_UNCOMPRESSED.try_into().unwrap();
+
+            secp256k1_fe_get_b32(&mut u.data[1], &ge.x);
+            secp256k1_fe_get_b32(&mut u.data[33], &ge.y);
+
+            u
+        }
+    }
+
+    /// Encode the point in SEC1 format, either compressed (33 bytes) or uncompressed (65 bytes), or a single zero byte for the identity
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        if compressed {
+            self.compress().as_bytes().to_vec()
+        } else {
+            self.uncompressed().as_bytes().to_vec()
+        }
+    }
+
+    /// Decode a point from any valid SEC1 encoding, whether identity, compressed, uncompressed or hybrid
+    pub fn from_sec1(bytes: &[u8]) -> Result<Point, Error> {
+        match bytes.len() {
+            1 => Point::try_from(&Compressed::try_from(bytes)?),
+            33 => {
+                let c = Compressed::try_from(bytes)?;
+                let tag = c.data[0] as u32;
+
+                if tag != SECP256K1_TAG_PUBKEY_EVEN && tag != SECP256K1_TAG_PUBKEY_ODD {
+                    return Err(Error::Conversion(ConversionError::BadGroupElement));
+                }
+
+                Point::try_from(&c)
+            }
+            65 => Point::try_from(&Uncompressed::try_from(bytes)?),
+            n => Err(Error::Conversion(ConversionError::WrongNumberOfBytes(n))),
+        }
+    }
+
     /// Perform a multi-exponentiation operation on the passed scalars and points, using the Pipperger algorithm
     pub fn multimult(scalars: Vec<Scalar>, points: Vec<Point>) -> Result<Point, Error> {
         let mut sp = ScalarsPoints {
@@ -248,69 +476,152 @@ impl Point {
 
     /// Return true if the y coordinate of this point is even
     pub fn has_even_y(&self) -> bool {
-        let mut ge = secp256k1_ge {
-            x: secp256k1_fe { n: [0; 5] },
-            y: secp256k1_fe { n: [0; 5] },
-            infinity: 0,
-        };
-
-        unsafe {
-            secp256k1_ge_set_gej(&mut ge, &self.gej);
-            secp256k1_fe_normalize_var(&mut ge.y);
-            secp256k1_fe_is_odd(&ge.y) == 0
-        }
+        unsafe { secp256k1_fe_is_odd(&self.affine().y) == 0 }
     }
 
     /// Return the x coord of this point as a normalized field element
     pub fn x(&self) -> field::Element {
-        let mut ge = secp256k1_ge {
-            x: secp256k1_fe { n: [0; 5] },
-            y: secp256k1_fe { n: [0; 5] },
-            infinity: 0,
-        };
-
-        unsafe {
-            secp256k1_ge_set_gej(&mut ge, &self.gej);
-            secp256k1_fe_normalize_var(&mut ge.x);
-            field::Element { fe: ge.x }
+        field::Element {
+            fe: self.affine().x,
         }
     }
 
     /// Return the y coord of this point as a normalized field element
     pub fn y(&self) -> field::Element {
+        field::Element {
+            fe: self.affine().y,
+        }
+    }
+
+    /// return the point P for which x(P) = x and has_even_y(P), or fails if x is greater than p-1 or no such point exists
+    pub fn lift_x(x: &field::Element) -> Result<Point, Error> {
+        let c = x * x * x + field::Element::from(7);
+        let y = c.sqrt().ok_or(Error::LiftFailed)?;
+
+        let point = Point::from_affine_unchecked(x, &y);
+        if point.has_even_y() {
+            Ok(point)
+        } else {
+            Ok(Point::from_affine_unchecked(x, &-y))
+        }
+    }
+
+    /// Return the BIP340 x-only encoding of this point and the parity of its y coordinate; the identity encodes as 32 zero bytes with even parity
+    pub fn to_xonly(&self) -> ([u8; 32], Parity) {
+        if self.is_identity() {
+            return ([0; 32], Parity::Even);
+        }
+
+        let ge = self.affine();
+        let mut x = [0u8; 32];
+
+        unsafe {
+            secp256k1_fe_get_b32(&mut x[0], &ge.x);
+        }
+
+        (x, Parity::from(unsafe { secp256k1_fe_is_odd(&ge.y) } == 1))
+    }
+
+    /// Decode a BIP340 x-only encoding into the point with that x coordinate and an even y coordinate
+    pub fn from_xonly(bytes: &[u8; 32]) -> Result<Point, Error> {
+        match field::Element::from_canonical_bytes(bytes) {
+            Ok(x) => Point::lift_x(&x),
+            Err(_) => Err(Error::Conversion(ConversionError::BadFieldElement)),
+        }
+    }
+
+    /// Return the point with the same x coordinate and an even y coordinate, negating if needed, along with the parity this point had
+    pub fn to_even_y(&self) -> (Point, Parity) {
+        if self.has_even_y() {
+            (*self, Parity::Even)
+        } else {
+            (-self, Parity::Odd)
+        }
+    }
+
+    /// Construct a point from the internal 64 byte representation shared by secp256k1_pubkey and secp256k1_xonly_pubkey
+    fn from_storage(data: &[u8; 64]) -> Point {
         let mut ge = secp256k1_ge {
             x: secp256k1_fe { n: [0; 5] },
             y: secp256k1_fe { n: [0; 5] },
             infinity: 0,
         };
+        let mut r = Point::new();
 
+        secp256k1_pubkey_load(&mut ge, data);
         unsafe {
-            secp256k1_ge_set_gej(&mut ge, &self.gej);
-            secp256k1_fe_normalize_var(&mut ge.y);
-            field::Element { fe: ge.y }
+            secp256k1_gej_set_ge(&mut r.gej, &ge);
         }
+
+        r
     }
 
-    /// return the point P for which x(P) = x and has_even_y(P), or fails if x is greater than p-1 or no such point exists
-    pub fn lift_x(x: &field::Element) -> Result<Point, Error> {
-        let fp = field::Element::from(field::P);
-        let f4 = field::Element::from(4);
-        let f1 = field::Element::from(1);
-        let fp14 = (fp + f1) * f4.invert();
+    /// Encode this point with BIP324 ElligatorSwift as 64 bytes which are indistinguishable from uniformly random, failing for the identity
+    pub fn to_ellswift<T: RngCore + CryptoRng>(&self, rng: &mut T) -> Result<[u8; 64], Error> {
+        ellswift::encode(self, rng)
+    }
 
-        let c = x * x * x + field::Element::from(7);
-        let y = c ^ fp14;
+    /// Decode a BIP324 ElligatorSwift encoding; every 64 byte string decodes to some point
+    pub fn from_ellswift(bytes: &[u8; 64]) -> Point {
+        ellswift::decode(bytes)
+    }
 
-        if c != y * y {
-            return Err(Error::LiftFailed);
-        }
+    /// Hash msg to a point using the RFC 9380 secp256k1_XMD:SHA-256_SSWU_RO_ suite with the passed domain separation tag
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Point {
+        hash_to_curve::hash_to_curve(msg, dst)
+    }
 
-        let point = Point::from((*x, y));
-        if point.has_even_y() {
+    /// Encode msg to a point using the RFC 9380 secp256k1_XMD:SHA-256_SSWU_NU_ suite with the passed domain separation tag
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Point {
+        hash_to_curve::encode_to_curve(msg, dst)
+    }
+
+    /// Return the nothing-up-my-sleeve point for the passed domain and index, whose discrete log relative to G is unknown; see generators::nums
+    pub fn nums(domain: &[u8], index: u32) -> Point {
+        generators::nums(domain, index)
+    }
+
+    /// Construct a point from affine coordinates, or fail if they do not satisfy the curve equation y^2 = x^3 + 7
+    pub fn from_affine(x: &field::Element, y: &field::Element) -> Result<Point, Error> {
+        let point = Point::from_affine_unchecked(x, y);
+
+        if point.is_on_curve() {
             Ok(point)
         } else {
-            Ok(Point::from((*x, fp - y)))
+            Err(Error::Conversion(ConversionError::BadGroupElement))
+        }
+    }
+
+    /// Construct a point from affine coordinates without checking that they lie on the curve
+    pub fn from_affine_unchecked(x: &field::Element, y: &field::Element) -> Point {
+        let ge = secp256k1_ge {
+            x: x.normalize().fe,
+            y: y.normalize().fe,
+            infinity: 0,
+        };
+
+        let mut r = Point::new();
+
+        unsafe {
+            secp256k1_gej_set_ge(&mut r.gej, &ge);
+        }
+
+        r
+    }
+
+    /// Return true if the point satisfies the curve equation, which in Jacobian coordinates is Y^2 = X^3 + 7Z^6.  The identity is always on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_identity() {
+            return true;
         }
+
+        let x = field::Element { fe: self.gej.x };
+        let y = field::Element { fe: self.gej.y };
+        let z = field::Element { fe: self.gej.z };
+        let z2 = z * z;
+        let z6 = z2 * z2 * z2;
+
+        y * y == x * x * x + field::Element::from(7) * z6
     }
 }
 
@@ -332,63 +643,86 @@ impl Debug for Point {
 
 impl Display for Point {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let c = self.clone().compress();
-        write!(f, "{}", bs58::encode(c.data).into_string())
+        let c = self.compress();
+        write!(f, "{}", bs58::encode(c.as_bytes()).into_string())
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match bs58::decode(s).into_vec() {
+            Ok(bytes) => Point::from_sec1(&bytes[..]),
+            Err(_e) => Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Decode,
+            ))),
+        }
     }
 }
 
 impl PartialEq for Point {
+    /// Compare Jacobian coordinates by cross-multiplying with Z, which avoids a full point subtraction
     fn eq(&self, other: &Self) -> bool {
-        let p = self - other;
+        match (self.is_identity(), other.is_identity()) {
+            (true, true) => return true,
+            (false, false) => {}
+            _ => return false,
+        }
+
+        let z1 = field::Element { fe: self.gej.z };
+        let z2 = field::Element { fe: other.gej.z };
+        let z1z1 = z1 * z1;
+        let z2z2 = z2 * z2;
+
+        let x1 = field::Element { fe: self.gej.x } * z2z2;
+        let x2 = field::Element { fe: other.gej.x } * z1z1;
+        if x1 != x2 {
+            return false;
+        }
 
-        p.gej.infinity == 1
+        let y1 = field::Element { fe: self.gej.y } * z2z2 * z2;
+        let y2 = field::Element { fe: other.gej.y } * z1z1 * z1;
+
+        y1 == y2
     }
 }
 
 impl Eq for Point {}
 
-impl Serialize for Point {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(self.compress().as_bytes())
-    }
-}
+/// Points are encoded in compressed SEC1 format, though uncompressed SEC1 is also accepted when decoding
+impl StringFormats for Point {
+    type Bytes = Compressed;
+    type Error = Error;
 
-struct PointVisitor;
+    const DESCRIPTION: &'static str = "a point on the secp256k1 curve";
 
-impl<'de> Visitor<'de> for PointVisitor {
-    type Value = Point;
+    fn encode_bytes(&self) -> Compressed {
+        self.compress()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an array of bytes which represents a point on the secp256k1 curve")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Point::from_sec1(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match Compressed::try_from(value) {
-            Ok(c) => match Point::try_from(&c) {
-                Ok(p) => Ok(p),
-                Err(e) => Err(E::custom(format!("{:?}", e))),
-            },
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<33> for Point {
+    fn to_fixed_bytes(&self) -> [u8; 33] {
+        self.compress().data
+    }
+
+    fn from_fixed_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Point::try_from(&Compressed::from_fixed_bytes(bytes)?)
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for Point {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -397,7 +731,7 @@ impl<'de> Deserialize<'de> for Point {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(PointVisitor)
+        traits::deserialize(deserializer)
     }
 }
 
@@ -407,30 +741,25 @@ impl Hash for Point {
     }
 }
 
-impl From<(Scalar, Scalar)> for Point {
-    fn from(ss: (Scalar, Scalar)) -> Self {
-        let x = field::Element::from(ss.0);
-        let y = field::Element::from(ss.0);
-
-        Self::from((x, y))
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl From<(field::Element, field::Element)> for Point {
-    fn from(ff: (field::Element, field::Element)) -> Self {
-        unsafe {
-            let ge = secp256k1_ge {
-                x: ff.0.fe,
-                y: ff.1.fe,
-                infinity: 0,
-            };
-
-            let mut r = Point::new();
+impl Ord for Point {
+    /// Order points by their canonical compressed encoding, so the identity sorts first
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compress().data.cmp(&other.compress().data)
+    }
+}
 
-            secp256k1_gej_set_ge(&mut r.gej, &ge);
+impl TryFrom<(field::Element, field::Element)> for Point {
+    type Error = Error;
 
-            r
-        }
+    /// Construct a point from affine coordinates, checking that they lie on the curve
+    fn try_from(ff: (field::Element, field::Element)) -> Result<Self, Self::Error> {
+        Point::from_affine(&ff.0, &ff.1)
     }
 }
 
@@ -466,6 +795,14 @@ impl TryFrom<&Compressed> for Point {
     type Error = Error;
 
     fn try_from(c: &Compressed) -> Result<Self, Self::Error> {
+        if c.data[0] == 0 {
+            return if c.is_identity() {
+                Ok(Point::identity())
+            } else {
+                Err(Error::Conversion(ConversionError::BadGroupElement))
+            };
+        }
+
         unsafe {
             let mut y = secp256k1_ge {
                 x: secp256k1_fe { n: [0; 5] },
@@ -498,12 +835,49 @@ impl TryFrom<&Compressed> for Point {
     }
 }
 
+impl TryFrom<&Uncompressed> for Point {
+    type Error = Error;
+
+    /// Decode an uncompressed or hybrid point, checking that it lies on the curve
+    fn try_from(u: &Uncompressed) -> Result<Self, Self::Error> {
+        if u.is_identity() {
+            return Ok(Point::identity());
+        }
+
+        let tag = u.data[0] as u32;
+
+        if tag != SECP256K1_TAG_PUBKEY_UNCOMPRESSED
+            && tag != SECP256K1_TAG_PUBKEY_HYBRID_EVEN
+            && tag != SECP256K1_TAG_PUBKEY_HYBRID_ODD
+        {
+            return Err(Error::Conversion(ConversionError::BadGroupElement));
+        }
+
+        let mut xb = [0u8; 32];
+        let mut yb = [0u8; 32];
+
+        xb.copy_from_slice(&u.data[1..33]);
+        yb.copy_from_slice(&u.data[33..65]);
+
+        let x = field::Element::from_canonical_bytes(&xb)
+            .map_err(|_| Error::Conversion(ConversionError::BadFieldElement))?;
+        let y = field::Element::from_canonical_bytes(&yb)
+            .map_err(|_| Error::Conversion(ConversionError::BadFieldElement))?;
+
+        if tag != SECP256K1_TAG_PUBKEY_UNCOMPRESSED
+            && y.is_odd() != (tag == SECP256K1_TAG_PUBKEY_HYBRID_ODD)
+        {
+            return Err(Error::Conversion(ConversionError::BadGroupElement));
+        }
+
+        Point::from_affine(&x, &y)
+    }
+}
+
 impl From<&PublicKey> for Point {
+    /// Load the key's internal representation directly, which is always a valid non-identity point
     fn from(key: &PublicKey) -> Self {
-        let compressed = Compressed::from(key.to_bytes());
-        // the unwrap should not fail because secp256k1_pubkey holds a parsed and valid public key,
-        // and PublicKey::key is marked pub(crate) so it can't be altered outside of this library
-        Self::try_from(&compressed).unwrap()
+        Self::from_storage(&key.key.data)
     }
 }
 
@@ -513,6 +887,19 @@ impl From<PublicKey> for Point {
     }
 }
 
+impl From<&XOnlyPublicKey> for Point {
+    /// Load the key's internal representation directly, giving the point with even y
+    fn from(key: &XOnlyPublicKey) -> Self {
+        Self::from_storage(&key.key.data)
+    }
+}
+
+impl From<XOnlyPublicKey> for Point {
+    fn from(key: XOnlyPublicKey) -> Self {
+        Self::from(&key)
+    }
+}
+
 impl Add for Point {
     type Output = Self;
 
@@ -684,7 +1071,7 @@ impl Zero for Point {
         Point::identity()
     }
     fn is_zero(&self) -> bool {
-        self == &Point::identity()
+        self.is_identity()
     }
 }
 
@@ -695,14 +1082,26 @@ pub struct Compressed {
 }
 
 impl Compressed {
-    /// Return a byte slice of the data
+    /// Return a byte slice of the data, which is a single zero byte for the identity
     pub fn as_bytes(&self) -> &[u8] {
+        let len = if self.is_identity() { 1 } else { 33 };
         let up: *const u8 = self.data.as_ptr();
         #[allow(clippy::size_of_in_element_count)]
-        let bs: &[u8] = unsafe { slice::from_raw_parts(up, mem::size_of::<u8>() * 33) };
+        let bs: &[u8] = unsafe { slice::from_raw_parts(up, mem::size_of::<u8>() * len) };
 
         bs
     }
+
+    /// Return true if this is the encoding of the identity point
+    pub fn is_identity(&self) -> bool {
+        self.data.iter().all(|b| *b == 0)
+    }
+}
+
+impl AsRef<[u8]> for Compressed {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
 }
 
 impl From<[u8; 33]> for Compressed {
@@ -713,7 +1112,7 @@ impl From<[u8; 33]> for Compressed {
 
 impl From<Compressed> for String {
     fn from(c: Compressed) -> String {
-        bs58::encode(c.data).into_string()
+        bs58::encode(c.as_bytes()).into_string()
     }
 }
 
@@ -721,6 +1120,9 @@ impl TryFrom<&[u8]> for Compressed {
     type Error = Error;
     fn try_from(bytes: &[u8]) -> Result<Self, Error> {
         match bytes.len() {
+            1 if bytes[0] == 0 => Ok(Compressed { data: [0; 33] }),
+            // the identity is only ever encoded as the single byte 0x00
+            33 if bytes[0] == 0 => Err(Error::Conversion(ConversionError::BadGroupElement)),
             33 => {
                 let mut r = Compressed { data: [0; 33] };
 
@@ -744,10 +1146,236 @@ impl TryFrom<&str> for Compressed {
     }
 }
 
+impl FromStr for Compressed {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Compressed::try_from(s)
+    }
+}
+
+/// Compressed points are encoded as their bytes, which for the identity is a single zero byte
+impl StringFormats for Compressed {
+    type Bytes = Vec<u8>;
+    type Error = Error;
+
+    const DESCRIPTION: &'static str = "a compressed secp256k1 point";
+
+    fn encode_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Compressed::try_from(bytes)
+    }
+}
+
+impl FixedBytes<33> for Compressed {
+    fn to_fixed_bytes(&self) -> [u8; 33] {
+        self.data
+    }
+
+    /// The identity is padded out to 33 zero bytes here, which try_from rejects
+    fn from_fixed_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        if bytes.iter().all(|b| *b == 0) {
+            Ok(Compressed { data: [0; 33] })
+        } else {
+            Compressed::try_from(&bytes[..])
+        }
+    }
+}
+
+impl Serialize for Compressed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        traits::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Compressed {
+    fn deserialize<D>(deserializer: D) -> Result<Compressed, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        traits::deserialize(deserializer)
+    }
+}
+
+/// A Point in uncompressed binary format
+pub struct Uncompressed {
+    /// The raw bytes of the uncompressed point
+    pub data: [u8; 65],
+}
+
+impl Uncompressed {
+    /// Return a byte slice of the data, which is a single zero byte for the identity
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.is_identity() {
+            &self.data[..1]
+        } else {
+            &self.data[..]
+        }
+    }
+
+    /// Return true if this is the encoding of the identity point
+    pub fn is_identity(&self) -> bool {
+        self.data.iter().all(|b| *b == 0)
+    }
+}
+
+/// Uncompressed points are encoded as their bytes, which for the identity is a single zero byte
+impl StringFormats for Uncompressed {
+    type Bytes = Vec<u8>;
+    type Error = Error;
+
+    const DESCRIPTION: &'static str = "an uncompressed secp256k1 point";
+
+    fn encode_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Uncompressed::try_from(bytes)
+    }
+}
+
+impl From<[u8; 65]> for Uncompressed {
+    fn from(bytes: [u8; 65]) -> Self {
+        Self { data: bytes }
+    }
+}
+
+impl From<Uncompressed> for String {
+    fn from(u: Uncompressed) -> String {
+        bs58::encode(u.as_bytes()).into_string()
+    }
+}
+
+impl TryFrom<&[u8]> for Uncompressed {
+    type Error = Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.len() {
+            1 if bytes[0] == 0 => Ok(Uncompressed { data: [0; 65] }),
+            // the identity is only ever encoded as the single byte 0x00
+            65 if bytes[0] == 0 => Err(Error::Conversion(ConversionError::BadGroupElement)),
+            65 => {
+                let mut r = Uncompressed { data: [0; 65] };
+
+                r.data.clone_from_slice(bytes);
+                Ok(r)
+            }
+            n => Err(Error::Conversion(ConversionError::WrongNumberOfBytes(n))),
+        }
+    }
+}
+
+impl TryFrom<&str> for Uncompressed {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self, Error> {
+        match bs58::decode(s).into_vec() {
+            Ok(bytes) => Uncompressed::try_from(&bytes[..]),
+            Err(_e) => Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Decode, //(e),
+            ))),
+        }
+    }
+}
+
+impl FromStr for Uncompressed {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Uncompressed::try_from(s)
+    }
+}
+
+/// Serde support for `Vec<Point>` using batch compression, with the same wire format as the default; only serializing shares work, as decompression has none to share.  A plain `Vec<Point>` still serializes point by point, so batching is opt-in: use `#[serde(with = "p256k1::point::batch")]` on the field, or the Points newtype
+pub mod batch {
+    use super::*;
+
+    /// Serialize the points as a sequence of compressed encodings, sharing a single field inversion
+    pub fn serialize<S>(points: &[Point], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(Point::compress_batch(points))
+    }
+
+    /// Deserialize a sequence of compressed encodings, reporting the index of the first invalid point
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Point>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let compressed = Vec::<Compressed>::deserialize(deserializer)?;
+
+        Point::decompress_batch(&compressed)
+            .map_err(|(i, e)| de::Error::custom(format!("point {}: {:?}", i, e)))
+    }
+}
+
+/// A vector of points whose serde uses the batch module, so serializing shares a single field inversion; the wire format is the same as `Vec<Point>`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Points(pub Vec<Point>);
+
+impl Deref for Points {
+    type Target = Vec<Point>;
+
+    fn deref(&self) -> &Vec<Point> {
+        &self.0
+    }
+}
+
+impl DerefMut for Points {
+    fn deref_mut(&mut self) -> &mut Vec<Point> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Point>> for Points {
+    fn from(points: Vec<Point>) -> Self {
+        Self(points)
+    }
+}
+
+impl From<Points> for Vec<Point> {
+    fn from(points: Points) -> Self {
+        points.0
+    }
+}
+
+impl FromIterator<Point> for Points {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Serialize for Points {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        batch::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Points {
+    fn deserialize<D>(deserializer: D) -> Result<Points, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        batch::deserialize(deserializer).map(Points)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand_core::OsRng;
+    use std::collections::{BTreeMap, HashSet};
     use std::thread;
 
     #[test]
@@ -825,6 +1453,215 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_identity() {
+        let mut rng = OsRng::default();
+        let p = Point::from(Scalar::random(&mut rng));
+
+        assert!(Point::identity().is_identity());
+        assert!((p - p).is_identity());
+        assert!(!p.is_identity());
+        assert!(Point::zero().is_zero());
+        assert!(!p.is_zero());
+    }
+
+    #[test]
+    fn double() {
+        let mut rng = OsRng::default();
+
+        assert!(Point::identity().double().is_identity());
+
+        for _ in 0..0xff {
+            let x = Scalar::random(&mut rng);
+            let p = Point::from(x);
+            let d = p.double();
+
+            assert!(d.is_on_curve());
+            assert_eq!(d, p + p);
+            assert_eq!(d, Point::from(x + x));
+            assert_eq!(d.double(), Scalar::from(4) * p);
+        }
+    }
+
+    #[test]
+    fn mul_u64() {
+        let mut rng = OsRng::default();
+        let p = Point::from(Scalar::random(&mut rng));
+
+        assert!(p.mul_u64(0).is_identity());
+        assert_eq!(p.mul_u64(1), p);
+        assert_eq!(p.mul_u64(2), p.double());
+
+        for k in (0..0xff).map(|_| rng.next_u64()).chain([u64::MAX]) {
+            let mut bytes = [0u8; 32];
+            bytes[24..].copy_from_slice(&k.to_be_bytes());
+
+            assert_eq!(p.mul_u64(k), Scalar::from(bytes) * p);
+        }
+    }
+
+    #[test]
+    fn eq() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0xff {
+            let x = Scalar::random(&mut rng);
+            let p = Point::from(x);
+            let q = Point::from_affine(&p.x(), &p.y()).unwrap();
+
+            assert_eq!(p, q);
+            assert_eq!(q, p);
+            assert_ne!(p, -p);
+            assert_ne!(p, p.double());
+            assert_ne!(p, Point::identity());
+            assert_ne!(Point::identity(), p);
+        }
+
+        assert_eq!(Point::identity(), Point::identity());
+    }
+
+    #[test]
+    fn canonical() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0xff {
+            let p = Point::from(Scalar::random(&mut rng)) + Point::G();
+            let c = p.canonical();
+
+            assert!(!p.is_normalized());
+            assert!(c.is_normalized());
+            assert_eq!(p, c);
+            assert_eq!(p.compress().data, c.compress().data);
+            assert_eq!(p.uncompressed().data, c.uncompressed().data);
+            assert_eq!(p.x(), c.x());
+            assert_eq!(p.y(), c.y());
+            assert_eq!(p.has_even_y(), c.has_even_y());
+            assert_eq!(c, c.canonical());
+        }
+
+        assert!(Point::identity().canonical().is_identity());
+    }
+
+    #[test]
+    fn ord() {
+        let mut rng = OsRng::default();
+        let mut points: Vec<Point> = (0..0xff)
+            .map(|_| Point::from(Scalar::random(&mut rng)))
+            .collect();
+        points.push(Point::identity());
+
+        let mut sorted = points.clone();
+        sorted.sort();
+
+        assert_eq!(sorted[0], Point::identity());
+        for w in sorted.windows(2) {
+            assert!(w[0].compress().data < w[1].compress().data);
+        }
+
+        for p in &points {
+            let c = p.canonical();
+            assert_eq!(p.cmp(&c), Ordering::Equal);
+            assert_eq!(p.cmp(&-p), p.compress().data.cmp(&(-p).compress().data));
+        }
+
+        let map: BTreeMap<Point, usize> = points.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(map.get(&p.canonical()), Some(&i));
+        }
+    }
+
+    #[test]
+    fn compress_batch() {
+        let mut rng = OsRng::default();
+        let mut points: Vec<Point> = (0..0xff)
+            .map(|_| Point::from(Scalar::random(&mut rng)) + Point::G())
+            .collect();
+        points.push(Point::identity());
+        points.push(Point::G().canonical());
+        points.insert(7, Point::identity());
+
+        let compressed = Point::compress_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        for (p, c) in points.iter().zip(compressed.iter()) {
+            assert_eq!(p.compress().data, c.data);
+        }
+
+        let decompressed = Point::decompress_batch(&compressed).unwrap();
+        assert_eq!(decompressed, points);
+        assert!(decompressed
+            .iter()
+            .all(|p| p.is_identity() || p.is_normalized()));
+
+        assert!(Point::compress_batch(&[]).is_empty());
+
+        let mut bad = Point::compress_batch(&points);
+        bad[3].data[0] = 0x05;
+        match Point::decompress_batch(&bad) {
+            Err((3, _)) => {}
+            r => panic!("expected failure at index 3, got {:?}", r.map(|v| v.len())),
+        }
+    }
+
+    #[test]
+    fn serde_batch() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            #[serde(with = "batch")]
+            points: Vec<Point>,
+        }
+
+        let mut rng = OsRng::default();
+        let mut points: Vec<Point> = (0..0x20)
+            .map(|_| Point::from(Scalar::random(&mut rng)) + Point::G())
+            .collect();
+        points.push(Point::identity());
+
+        let json = serde_json::to_string(&Wrapper {
+            points: points.clone(),
+        })
+        .expect("failed to serialize");
+        let plain = format!(
+            "{{\"points\":{}}}",
+            serde_json::to_string(&points).expect("failed to serialize")
+        );
+        assert_eq!(json, plain);
+
+        let q: Wrapper = serde_json::from_str(&json).expect("failed to deserialize");
+        assert_eq!(q.points, points);
+
+        let bad = json.replacen("\"03", "\"05", 1).replacen("\"02", "\"05", 1);
+        assert!(serde_json::from_str::<Wrapper>(&bad).is_err());
+
+        let wrapped = Points::from(points.clone());
+        let json = serde_json::to_string(&wrapped).expect("failed to serialize");
+        assert_eq!(
+            json,
+            serde_json::to_string(&points).expect("failed to serialize")
+        );
+        let q: Points = serde_json::from_str(&json).expect("failed to deserialize");
+        assert_eq!(q, wrapped);
+
+        let bytes = bincode::serialize(&wrapped).expect("failed to serialize");
+        assert_eq!(
+            bytes,
+            bincode::serialize(&points).expect("failed to serialize")
+        );
+        let q: Points = bincode::deserialize(&bytes).expect("failed to deserialize");
+        assert_eq!(Vec::from(q), points);
+    }
+
+    #[test]
+    fn hash() {
+        let mut rng = OsRng::default();
+        let mut set = HashSet::new();
+
+        for _ in 0..0xff {
+            let p = Point::from(Scalar::random(&mut rng)) + Point::G();
+            set.insert(p);
+            assert!(set.contains(&p.canonical()));
+        }
+    }
+
     #[test]
     fn add_assign() {
         let mut rng = OsRng::default();
@@ -952,6 +1789,120 @@ mod tests {
         }
     }
 
+    #[test]
+    fn string_formats() {
+        let g = Point::G();
+        let hex = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let check = "5p78kHbL33Rn3JWkTWRE2B9uz6gy4r1KbfAKLNQGE3ovKxJ2W1";
+
+        assert_eq!(g.to_hex(), hex);
+        assert_eq!(Point::from_hex(hex).unwrap(), g);
+        assert_eq!(g.to_base58check(), check);
+        assert_eq!(Point::from_base58check(check).unwrap(), g);
+        assert_eq!(Point::from_str(&g.to_string()).unwrap(), g);
+
+        let c = g.compress();
+        assert_eq!(c.to_hex(), hex);
+        assert_eq!(Compressed::from_base58check(check).unwrap().data, c.data);
+        assert_eq!(
+            Compressed::from_str(&String::from(g.compress()))
+                .unwrap()
+                .data,
+            c.data
+        );
+
+        let u = g.uncompressed();
+        assert_eq!(Uncompressed::from_hex(&u.to_hex()).unwrap().data, u.data);
+        assert_eq!(
+            Uncompressed::from_base58check(&u.to_base58check())
+                .unwrap()
+                .data,
+            u.data
+        );
+
+        let identity = Point::identity();
+        assert_eq!(identity.to_hex(), "00");
+        assert_eq!(Point::from_hex("00").unwrap(), identity);
+
+        assert!(matches!(
+            Point::from_base58check("5p78kHbL33Rn3JWkTWRE2B9uz6gy4r1KbfAKLNQGE3ovKxJ2W2"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        assert!(Point::from_hex(&hex[2..]).is_err());
+    }
+
+    #[test]
+    fn sec1() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0xff {
+            let p = Point::from(Scalar::random(&mut rng));
+            let key = PublicKey::try_from(&p.compress().data[..]).unwrap();
+
+            let c = p.to_sec1(true);
+            assert_eq!(c.len(), 33);
+            assert_eq!(c, key.to_bytes());
+            assert_eq!(Point::from_sec1(&c).unwrap(), p);
+
+            let u = p.to_sec1(false);
+            assert_eq!(u.len(), 65);
+            assert_eq!(u, key.to_uncompressed_bytes());
+            assert_eq!(Point::from_sec1(&u).unwrap(), p);
+
+            let s: String = p.uncompressed().into();
+            let v = Uncompressed::try_from(s.as_str()).unwrap();
+            assert_eq!(Point::try_from(&v).unwrap(), p);
+
+            let mut h = u.clone();
+            h[0] = if p.has_even_y() { 6 } else { 7 };
+            assert_eq!(Point::from_sec1(&h).unwrap(), p);
+
+            h[0] ^= 1;
+            assert!(Point::from_sec1(&h).is_err());
+
+            let mut bad = u.clone();
+            bad[64] ^= 1;
+            assert!(Point::from_sec1(&bad).is_err());
+        }
+
+        let mut c = G.compress().data;
+        c[0] = 4;
+        assert!(Point::from_sec1(&c).is_err());
+
+        let mut u = G.uncompressed().data;
+        u[0] = 2;
+        assert!(Point::from_sec1(&u).is_err());
+
+        assert!(Point::from_sec1(&[4u8; 64]).is_err());
+    }
+
+    #[test]
+    fn affine() {
+        let mut rng = OsRng::default();
+
+        assert!(G.is_on_curve());
+        assert!(Point::identity().is_on_curve());
+
+        for _ in 0..0xff {
+            let p = Point::from(Scalar::random(&mut rng));
+            assert!(p.is_on_curve());
+
+            let q = Point::from_affine(&p.x(), &p.y()).unwrap();
+            assert_eq!(p, q);
+            assert!(q.is_on_curve());
+
+            let r = Point::try_from((p.x(), p.y())).unwrap();
+            assert_eq!(p, r);
+
+            let y = p.y() + field::Element::one();
+            assert!(Point::from_affine(&p.x(), &y).is_err());
+            assert!(Point::try_from((p.x(), y)).is_err());
+            assert!(!Point::from_affine_unchecked(&p.x(), &y).is_on_curve());
+        }
+    }
+
     #[test]
     fn custom_serde() {
         let mut rng = OsRng::default();
@@ -960,6 +1911,78 @@ mod tests {
         let q = serde_json::from_str(&s).expect("failed to deserialize");
 
         assert_eq!(p, q);
+        assert_eq!(s, format!("\"{}\"", p.to_hex()));
+
+        let a = serde_json::to_string(&p.compress().data.to_vec()).expect("failed to serialize");
+        assert_eq!(serde_json::from_str::<Point>(&a).unwrap(), p);
+        assert!(serde_json::from_str::<Point>("\"zz\"").is_err());
+
+        let b = bincode::serialize(&p).expect("failed to serialize");
+        assert_eq!(b.len(), 8 + 33);
+        assert_eq!(bincode::deserialize::<Point>(&b).unwrap(), p);
+
+        // bincode data written by earlier releases is a length prefixed byte string
+        let old = hex::decode(
+            "21000000000000000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )
+        .unwrap();
+        assert_eq!(bincode::deserialize::<Point>(&old).unwrap(), G);
+        assert_eq!(bincode::serialize(&G).unwrap(), old);
+
+        // the identity is padded out to 33 zero bytes in binary formats
+        let b = bincode::serialize(&Point::identity()).expect("failed to serialize");
+        assert_eq!(b[..8], 33u64.to_le_bytes());
+        assert_eq!(b[8..], [0; 33]);
+        assert_eq!(
+            bincode::deserialize::<Point>(&b).unwrap(),
+            Point::identity()
+        );
+        assert!(bincode::deserialize::<Point>(&b[..40]).is_err());
+    }
+
+    #[test]
+    fn identity_encoding() {
+        let mut rng = OsRng::default();
+        let p = Point::from(Scalar::random(&mut rng));
+        let zero = p - p;
+
+        let c = zero.compress();
+        assert!(c.is_identity());
+        assert_eq!(c.as_bytes(), &[0u8]);
+        assert_eq!(Point::try_from(&c).unwrap(), Point::identity());
+        assert_eq!(zero.to_sec1(true), vec![0u8]);
+        assert_eq!(zero.to_sec1(false), vec![0u8]);
+        assert_eq!(Point::from_sec1(&[0u8]).unwrap(), Point::identity());
+
+        let u = zero.uncompressed();
+        assert!(u.is_identity());
+        assert_eq!(Point::try_from(&u).unwrap(), Point::identity());
+
+        let s = format!("{}", &zero);
+        let t: String = zero.compress().into();
+        assert_eq!(s, t);
+        let d = Compressed::try_from(s.as_str()).unwrap();
+        assert_eq!(Point::try_from(&d).unwrap(), Point::identity());
+
+        let json = serde_json::to_string(&zero).expect("failed to serialize");
+        assert_eq!(json, "\"00\"");
+        let q: Point = serde_json::from_str(&json).expect("failed to deserialize");
+        assert_eq!(q, Point::identity());
+        assert_ne!(q, p);
+
+        let mut bad = [0u8; 33];
+        bad[32] = 1;
+        assert!(Point::try_from(&Compressed::from(bad)).is_err());
+        assert!(Point::from_sec1(&[1u8]).is_err());
+        assert!(Compressed::try_from(&[0u8, 0u8][..]).is_err());
+
+        // all zero encodings of full length are not the identity
+        assert!(Point::from_sec1(&[0u8; 33]).is_err());
+        assert!(Point::from_sec1(&[0u8; 65]).is_err());
+        assert!(Uncompressed::try_from(&[0u8; 65][..]).is_err());
+        assert!(Point::from_hex(&hex::encode([0u8; 33])).is_err());
+        let json = format!("\"{}\"", hex::encode([0u8; 33]));
+        assert!(serde_json::from_str::<Point>(&json).is_err());
     }
 
     #[test]
@@ -974,6 +1997,38 @@ mod tests {
         assert_eq!(point.has_even_y(), point.compress().data[0] == 2);
     }
 
+    #[test]
+    fn xonly() {
+        let mut rng = OsRng::default();
+
+        for _ in 0..0xff {
+            let p = Point::from(Scalar::random(&mut rng));
+            let (x, parity) = p.to_xonly();
+            let c = p.compress();
+
+            assert_eq!(x, c.data[1..]);
+            assert_eq!(parity.is_odd(), !p.has_even_y());
+
+            let q = Point::from_xonly(&x).unwrap();
+            let (e, even_parity) = p.to_even_y();
+
+            assert!(q.has_even_y());
+            assert_eq!(q, e);
+            assert_eq!(even_parity, parity);
+            assert_eq!(q.to_xonly(), (x, Parity::Even));
+            if parity.is_odd() {
+                assert_eq!(q, -p);
+            } else {
+                assert_eq!(q, p);
+            }
+        }
+
+        assert_eq!(Point::identity().to_xonly(), ([0; 32], Parity::Even));
+        assert!(Point::from_xonly(&[0xff; 32]).is_err());
+        assert_eq!(i32::from(Parity::Odd), 1);
+        assert_eq!(i32::from(Parity::Even), 0);
+    }
+
     #[test]
     fn from_public_key() {
         let mut rng = OsRng;
@@ -7,13 +7,12 @@ use core::{
     hash::{Hash, Hasher},
     iter::Sum,
     ops::{Add, AddAssign, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Sub},
+    str::FromStr,
 };
 use num_traits::{One, Zero};
 use rand_core::{CryptoRng, RngCore};
-use serde::{
-    de::{self, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
 
 use crate::_rename::{
     secp256k1_ecmult, secp256k1_scalar_add, secp256k1_scalar_eq, secp256k1_scalar_get_b32,
@@ -23,6 +22,7 @@ use crate::_rename::{
 use crate::bindings::secp256k1_scalar;
 
 use crate::point::Point;
+use crate::traits::{self, FixedBytes, StringFormats};
 
 use crate::errors::{Base58Error, ConversionError};
 
@@ -40,6 +40,12 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<ConversionError> for Error {
+    fn from(e: ConversionError) -> Self {
+        Error::Conversion(e)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 /**
 Scalar is a wrapper around libsecp256k1's internal secp256k1_scalar struct.  It provides a scalar modulo the group order.  Storing scalars in this format avoids unnecessary conversions from byte bffers, which provides a significant performance enhancement.
@@ -176,45 +182,34 @@ impl PartialEq for Scalar {
 
 impl Eq for Scalar {}
 
-impl Serialize for Scalar {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(&self.to_bytes())
-    }
-}
+/// Scalars are encoded as 32 big-endian bytes
+impl StringFormats for Scalar {
+    type Bytes = [u8; 32];
+    type Error = Error;
 
-struct ScalarVisitor;
+    const DESCRIPTION: &'static str = "a scalar for the secp256k1 curve";
 
-impl<'de> Visitor<'de> for ScalarVisitor {
-    type Value = Scalar;
+    fn encode_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an array of bytes which represents a scalar for the secp256k1 curve")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Scalar::try_from(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match Self::Value::try_from(value) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<32> for Scalar {
+    fn to_fixed_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -223,7 +218,13 @@ impl<'de> Deserialize<'de> for Scalar {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(ScalarVisitor)
+        traits::deserialize(deserializer)
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.scalar.d.zeroize();
     }
 }
 
@@ -284,6 +285,15 @@ impl TryFrom<&str> for Scalar {
     }
 }
 
+impl FromStr for Scalar {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Scalar::try_from(s)
+    }
+}
+
 impl From<Scalar> for String {
     fn from(s: Scalar) -> String {
         bs58::encode(s.to_bytes()).into_string()
@@ -683,6 +693,35 @@ mod tests {
     use super::*;
     use rand_core::OsRng;
 
+    #[test]
+    fn string_formats() {
+        let one = Scalar::from(1);
+        let hex = "0000000000000000000000000000000000000000000000000000000000000001";
+        let check = "11111111111111111111111111111118qjnEr";
+
+        assert_eq!(one.to_hex(), hex);
+        assert_eq!(Scalar::from_hex(hex).unwrap(), one);
+        assert_eq!(Scalar::from_hex(&hex.to_uppercase()).unwrap(), one);
+        assert_eq!(one.to_base58check(), check);
+        assert_eq!(Scalar::from_base58check(check).unwrap(), one);
+        assert_eq!(Scalar::from_str(&one.to_string()).unwrap(), one);
+
+        assert!(matches!(
+            Scalar::from_base58check("11111111111111111111111111111118qjnEs"),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        assert!(matches!(
+            Scalar::from_hex("xy"),
+            Err(Error::Conversion(ConversionError::Hex))
+        ));
+        assert!(matches!(
+            Scalar::from_hex("0001"),
+            Err(Error::Conversion(ConversionError::WrongNumberOfBytes(2)))
+        ));
+    }
+
     #[test]
     fn from() {
         for x in 0..0xff {
@@ -870,5 +909,25 @@ mod tests {
         let y = serde_json::from_str(&s).expect("failed to deserialize");
 
         assert_eq!(x, y);
+        assert_eq!(s, format!("\"{}\"", x.to_hex()));
+
+        // the array of bytes written before human readable formats were hex is still accepted
+        let a = serde_json::to_string(&x.to_bytes()).expect("failed to serialize");
+        assert_eq!(serde_json::from_str::<Scalar>(&a).unwrap(), x);
+
+        let b = bincode::serialize(&x).expect("failed to serialize");
+        assert_eq!(b.len(), 8 + 32);
+        assert_eq!(bincode::deserialize::<Scalar>(&b).unwrap(), x);
+
+        // bincode data written by earlier releases is a length prefixed byte string
+        let old = hex::decode(
+            "20000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        assert_eq!(
+            bincode::deserialize::<Scalar>(&old).unwrap(),
+            Scalar::from(1)
+        );
+        assert_eq!(bincode::serialize(&Scalar::from(1)).unwrap(), old);
     }
 }
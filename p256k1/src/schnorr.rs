@@ -1,16 +1,14 @@
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
-use serde::{
-    de::{self, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
-use std::{array::TryFromSliceError, hash::Hash};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{array::TryFromSliceError, hash::Hash, str::FromStr};
 
 use crate::_rename::{secp256k1_schnorrsig_sign32, secp256k1_schnorrsig_verify};
 use crate::{
     context::Context,
-    errors::ConversionError,
+    errors::{Base58Error, ConversionError},
     keys::{Error as KeyError, KeyPair, XOnlyPublicKey},
     scalar::Scalar,
+    traits::{self, FixedBytes, StringFormats},
 };
 
 #[derive(Debug, Clone)]
@@ -34,6 +32,12 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<ConversionError> for Error {
+    fn from(e: ConversionError) -> Self {
+        Error::Conversion(e)
+    }
+}
+
 impl From<TryFromSliceError> for Error {
     fn from(e: TryFromSliceError) -> Self {
         Error::TryFrom(e.to_string())
@@ -106,6 +110,20 @@ impl Display for Signature {
     }
 }
 
+impl FromStr for Signature {
+    type Err = Error;
+
+    /// Parse the bare base58 written by Display, which has no checksum; use from_hex or from_base58check for the other formats
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match bs58::decode(s).into_vec() {
+            Ok(bytes) => Signature::try_from(&bytes[..]),
+            Err(_e) => Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Decode,
+            ))),
+        }
+    }
+}
+
 impl PartialEq for Signature {
     fn eq(&self, other: &Self) -> bool {
         self.to_bytes().eq(&other.to_bytes())
@@ -132,45 +150,34 @@ impl Hash for Signature {
     }
 }
 
-impl Serialize for Signature {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_bytes(&self.to_bytes())
-    }
-}
+/// Signatures are encoded in the 64 byte BIP340 format
+impl StringFormats for Signature {
+    type Bytes = [u8; 64];
+    type Error = Error;
 
-struct SignatureVisitor;
+    const DESCRIPTION: &'static str = "a Schnorr signature";
 
-impl<'de> Visitor<'de> for SignatureVisitor {
-    type Value = Signature;
+    fn encode_bytes(&self) -> [u8; 64] {
+        self.to_bytes()
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an array of bytes which represents two scalars on the secp256k1 curve")
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Signature::try_from(bytes)
     }
+}
 
-    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match Signature::try_from(value) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(E::custom(format!("{:?}", e))),
-        }
+impl FixedBytes<64> for Signature {
+    fn to_fixed_bytes(&self) -> [u8; 64] {
+        self.to_bytes()
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        A: de::SeqAccess<'de>,
+        S: Serializer,
     {
-        let mut v = Vec::new();
-
-        while let Ok(Some(x)) = seq.next_element() {
-            v.push(x);
-        }
-
-        self.visit_bytes(&v)
+        traits::serialize(self, serializer)
     }
 }
 
@@ -179,7 +186,7 @@ impl<'de> Deserialize<'de> for Signature {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(SignatureVisitor)
+        traits::deserialize(deserializer)
     }
 }
 
@@ -207,6 +214,33 @@ mod tests {
     use rand_core::{OsRng, RngCore};
     use sha2::{Digest, Sha256};
 
+    #[test]
+    fn string_formats() {
+        let mut rnd = OsRng::default();
+        let sec_key = Scalar::random(&mut rnd);
+        let msg = Sha256::digest(b"string formats");
+        let sig = Signature::new(&msg, &sec_key).unwrap();
+
+        assert_eq!(sig.to_hex(), hex::encode(sig.to_bytes()));
+        assert_eq!(Signature::from_hex(&sig.to_hex()).unwrap(), sig);
+        assert_eq!(
+            Signature::from_base58check(&sig.to_base58check()).unwrap(),
+            sig
+        );
+        assert_eq!(Signature::from_str(&sig.to_string()).unwrap(), sig);
+
+        // flip a digit in the middle so the length is unchanged but the checksum no longer matches
+        let mut check = sig.to_base58check().into_bytes();
+        check[10] = if check[10] == b'2' { b'3' } else { b'2' };
+        assert!(matches!(
+            Signature::from_base58check(std::str::from_utf8(&check).unwrap()),
+            Err(Error::Conversion(ConversionError::Base58(
+                Base58Error::Checksum
+            )))
+        ));
+        assert!(Signature::from_hex("00").is_err());
+    }
+
     #[test]
     fn signature() {
         // Generate a secret and public key
@@ -0,0 +1,171 @@
+/*!
+Opt-in serde adapters which encode a value as its Base58Check string, in both human readable and binary formats.  The 4 byte checksum means a mistyped string fails to deserialize rather than decoding to a different value.
+
+Use them as `#[serde(with = "p256k1::serde_base58")]` on a Scalar, Point, Compressed, Uncompressed, PublicKey, XOnlyPublicKey, field Element or signature, or `#[serde(with = "p256k1::serde_base58::option")]` on an Option of one.
+*/
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+use crate::traits::StringFormats;
+
+/// Serialize the value as its Base58Check string
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: StringFormats,
+    S: Serializer,
+{
+    serializer.serialize_str(&value.to_base58check())
+}
+
+/// Deserialize the value from its Base58Check string, rejecting a bad checksum
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: StringFormats,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(Base58Visitor(PhantomData))
+}
+
+struct Base58Visitor<T>(PhantomData<T>);
+
+impl<'de, T: StringFormats> Visitor<'de> for Base58Visitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a base58check string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match T::from_base58check(value) {
+            Ok(t) => Ok(t),
+            Err(e) => Err(E::custom(format!("{:?}", e))),
+        }
+    }
+}
+
+/// Serde adapters for an optional value, encoded as a base58 string or none
+pub mod option {
+    use super::*;
+
+    /// Serialize the value as its Base58Check string, or as none
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: StringFormats,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_some(&v.to_base58check()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize the value from its Base58Check string, or from none
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: StringFormats,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor(PhantomData))
+    }
+
+    struct OptionVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: StringFormats> Visitor<'de> for OptionVisitor<T> {
+        type Value = Option<T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            formatter.write_str("an optional base58check string")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ecdsa,
+        keys::{PublicKey, XOnlyPublicKey},
+        point::Point,
+        scalar::Scalar,
+        traits::StringFormats,
+    };
+    use rand_core::OsRng;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message {
+        #[serde(with = "crate::serde_base58")]
+        scalar: Scalar,
+        #[serde(with = "crate::serde_base58")]
+        point: Point,
+        #[serde(with = "crate::serde_base58")]
+        public_key: PublicKey,
+        #[serde(with = "crate::serde_base58")]
+        xonly_public_key: XOnlyPublicKey,
+        #[serde(with = "crate::serde_base58")]
+        signature: ecdsa::Signature,
+        #[serde(with = "crate::serde_base58::option")]
+        tweak: Option<Scalar>,
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = OsRng;
+        let scalar = Scalar::random(&mut rng);
+        let msg = Message {
+            scalar,
+            point: Point::from(scalar),
+            public_key: PublicKey::new(&scalar).unwrap(),
+            xonly_public_key: XOnlyPublicKey::new(&scalar).unwrap(),
+            signature: ecdsa::Signature::new(&[1u8; 32], &scalar).unwrap(),
+            tweak: None,
+        };
+
+        let json = serde_json::to_string(&msg).expect("failed to serialize");
+        assert!(json.contains(&format!("\"scalar\":\"{}\"", scalar.to_base58check())));
+        assert!(json.contains("\"tweak\":null"));
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), msg);
+
+        let msg = Message {
+            tweak: Some(Scalar::from(7)),
+            ..msg
+        };
+        let json = serde_json::to_string(&msg).expect("failed to serialize");
+        let tweak = Scalar::from(7).to_base58check();
+        assert!(json.contains(&format!("\"tweak\":\"{}\"", tweak)));
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), msg);
+
+        let bytes = bincode::serialize(&msg).expect("failed to serialize");
+        assert_eq!(bincode::deserialize::<Message>(&bytes).unwrap(), msg);
+
+        let bad = json.replacen("\"tweak\":\"", "\"tweak\":\"0", 1);
+        assert!(serde_json::from_str::<Message>(&bad).is_err());
+
+        // a typo which is still valid base58 is caught by the checksum
+        let typo = if tweak.ends_with('1') { "2" } else { "1" };
+        let bad = json.replacen(&tweak, &format!("{}{}", &tweak[..tweak.len() - 1], typo), 1);
+        assert!(serde_json::from_str::<Message>(&bad).is_err());
+    }
+}
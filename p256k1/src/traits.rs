@@ -1,4 +1,15 @@
-use crate::{point::Point, scalar::Scalar};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+use zeroize::Zeroizing;
+
+use crate::{base58, errors::ConversionError, point::Point, scalar::Scalar};
 
 /// A trait which allows wrapping up types to be used in multimult without excessive copying
 pub trait MultiMult {
@@ -16,3 +27,164 @@ pub trait MultiMult {
         Some(self.get_size() * 512)
     }
 }
+
+/// Hex and Base58Check string formats, provided as default methods over a type's canonical byte encoding
+pub trait StringFormats: Sized {
+    /// The canonical byte encoding
+    type Bytes: AsRef<[u8]>;
+    /// The error returned when decoding fails
+    type Error: From<ConversionError> + Debug;
+
+    /// What the encoding represents, for serde error messages
+    const DESCRIPTION: &'static str;
+
+    /// Return the canonical byte encoding
+    fn encode_bytes(&self) -> Self::Bytes;
+
+    /// Decode the canonical byte encoding
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, Self::Error>;
+
+    /// Encode as lowercase hex
+    fn to_hex(&self) -> String {
+        hex::encode(self.encode_bytes())
+    }
+
+    /// Decode from hex, in either case
+    fn from_hex(s: &str) -> Result<Self, Self::Error> {
+        match hex::decode(s) {
+            Ok(bytes) => Self::decode_bytes(&Zeroizing::new(bytes)),
+            Err(_e) => Err(ConversionError::Hex.into()),
+        }
+    }
+
+    /// Encode as Base58Check, whose 4 byte checksum lets typos be detected
+    fn to_base58check(&self) -> String {
+        base58::encode_check(self.encode_bytes().as_ref())
+    }
+
+    /// Decode from Base58Check, reporting a bad checksum as Base58Error::Checksum
+    fn from_base58check(s: &str) -> Result<Self, Self::Error> {
+        match base58::decode_check(s) {
+            Ok(bytes) => Self::decode_bytes(&bytes),
+            Err(e) => Err(ConversionError::Base58(e).into()),
+        }
+    }
+}
+
+/// The fixed size encoding which serde writes as bytes for formats that are not human readable
+pub(crate) trait FixedBytes<const N: usize>: StringFormats {
+    /// Return the fixed size encoding
+    fn to_fixed_bytes(&self) -> [u8; N];
+
+    /// Decode the fixed size encoding
+    fn from_fixed_bytes(bytes: &[u8; N]) -> Result<Self, Self::Error> {
+        Self::decode_bytes(bytes)
+    }
+}
+
+/// Serialize as hex for human readable formats and as bytes otherwise, which keeps existing binary data readable; scratch copies are zeroed since the value may be a secret
+pub(crate) fn serialize<T, S, const N: usize>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: FixedBytes<N>,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&Zeroizing::new(value.to_hex()))
+    } else {
+        serializer.serialize_bytes(Zeroizing::new(value.to_fixed_bytes()).as_ref())
+    }
+}
+
+/// Deserialize what serialize produces; all formats also accept a sequence of bytes
+pub(crate) fn deserialize<'de, T, D, const N: usize>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FixedBytes<N>,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(StringFormatsVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(FixedBytesVisitor(PhantomData))
+    }
+}
+
+struct FixedBytesVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: FixedBytes<N>, const N: usize> Visitor<'de> for FixedBytesVisitor<T, N> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(formatter, "{} bytes which represent {}", N, T::DESCRIPTION)
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let bytes: &[u8; N] = value
+            .try_into()
+            .map_err(|_| E::invalid_length(value.len(), &self))?;
+
+        T::from_fixed_bytes(bytes).map_err(|e| E::custom(format!("{:?}", e)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut bytes = Zeroizing::new([0u8; N]);
+
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        T::from_fixed_bytes(&bytes).map_err(|e| de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+struct StringFormatsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: StringFormats> Visitor<'de> for StringFormatsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "a hex string or an array of bytes which represents {}",
+            T::DESCRIPTION
+        )
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_hex(value).map_err(|e| E::custom(format!("{:?}", e)))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::decode_bytes(value).map_err(|e| E::custom(format!("{:?}", e)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut v = Zeroizing::new(Vec::new());
+
+        while let Ok(Some(x)) = seq.next_element() {
+            v.push(x);
+        }
+
+        self.visit_bytes(&v)
+    }
+}
@@ -5,6 +5,7 @@
 #include "_secp256k1/include/secp256k1_preallocated.h"
 #include "_secp256k1/include/secp256k1_extrakeys.h"
 #include "_secp256k1/include/secp256k1_schnorrsig.h"
+#include "_secp256k1/include/secp256k1_ellswift.h"
 #include "_secp256k1/src/scalar.h"
 #include "_secp256k1/src/group.h"
 #include "_secp256k1/src/field_impl.h"
@@ -7,33 +7,53 @@ fn main() {
     const USER: &str = "bitcoin-core";
     const REPO_NAME: &str = "secp256k1";
     const COMMIT_SHA: &str = "2bca0a5cbf756dd4ff1f0bda4585a7d3c64e1480";
-
-    let url = &format!("https://github.com/{USER}/{REPO_NAME}/archive/{COMMIT_SHA}.zip");
+    const ELLSWIFT_COMMIT_SHA: &str = "199d27cea32203b224b208627533c2e813cd3b21";
 
     let output_dir = &format!("./p256k1/_{REPO_NAME}");
     if Path::new(output_dir).exists() {
         fs::remove_dir_all(output_dir).unwrap();
     }
 
-    // unpack
+    unpack(
+        &format!("https://github.com/{USER}/{REPO_NAME}/archive/{COMMIT_SHA}.zip"),
+        &format!("{REPO_NAME}-{COMMIT_SHA}"),
+        output_dir,
+    );
+
+    // vendor the ellswift module, which was only released after COMMIT_SHA
     {
-        const ZIP: &str = "tmp.zip";
-        Command::new("curl")
-            .arg("-L")
-            .arg("-o")
-            .arg(ZIP)
-            .arg(url)
-            .status_unwrap();
-
-        const TMP_DIR: &str = "tmp";
-        Command::new("unzip")
-            .arg("-d")
-            .arg(TMP_DIR)
-            .arg(ZIP)
-            .status_unwrap();
-        fs::remove_file(ZIP).unwrap();
-        fs::rename(format!("{TMP_DIR}/{REPO_NAME}-{COMMIT_SHA}"), output_dir).unwrap();
-        fs::remove_dir_all(TMP_DIR).unwrap();
+        const ELLSWIFT_DIR: &str = "tmp_ellswift";
+        unpack(
+            &format!("https://github.com/{USER}/{REPO_NAME}/archive/{ELLSWIFT_COMMIT_SHA}.zip"),
+            &format!("{REPO_NAME}-{ELLSWIFT_COMMIT_SHA}"),
+            ELLSWIFT_DIR,
+        );
+
+        const HEADER: &str = "include/secp256k1_ellswift.h";
+        fs::copy(
+            format!("{ELLSWIFT_DIR}/{HEADER}"),
+            format!("{output_dir}/{HEADER}"),
+        )
+        .unwrap();
+
+        const MODULE: &str = "src/modules/ellswift";
+        fs::rename(
+            format!("{ELLSWIFT_DIR}/{MODULE}"),
+            format!("{output_dir}/{MODULE}"),
+        )
+        .unwrap();
+        fs::remove_dir_all(ELLSWIFT_DIR).unwrap();
+
+        // the module needs a few internal helpers from the same release, see _ellswift_backports.h
+        let file_name = &format!("{output_dir}/src/secp256k1.c");
+        let mut source = fs::read_to_string(file_name).unwrap();
+        source.push_str(concat!(
+            "\n#ifdef ENABLE_MODULE_ELLSWIFT\n",
+            "# include \"../../_ellswift_backports.h\"\n",
+            "# include \"modules/ellswift/main_impl.h\"\n",
+            "#endif\n",
+        ));
+        fs::write(file_name, source).unwrap();
     }
 
     // apply patches
@@ -183,6 +203,27 @@ fn main() {
     }
 }
 
+/// Download the zip archive at url and move its top level directory from to the directory to
+fn unpack(url: &str, from: &str, to: &str) {
+    const ZIP: &str = "tmp.zip";
+    Command::new("curl")
+        .arg("-L")
+        .arg("-o")
+        .arg(ZIP)
+        .arg(url)
+        .status_unwrap();
+
+    const TMP_DIR: &str = "tmp";
+    Command::new("unzip")
+        .arg("-d")
+        .arg(TMP_DIR)
+        .arg(ZIP)
+        .status_unwrap();
+    fs::remove_file(ZIP).unwrap();
+    fs::rename(format!("{TMP_DIR}/{from}"), to).unwrap();
+    fs::remove_dir_all(TMP_DIR).unwrap();
+}
+
 trait CommandEx {
     fn status_unwrap(&mut self);
 }